@@ -0,0 +1,107 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{spanned::Spanned, Data, DeriveInput, Fields, LitStr};
+
+pub fn derive_animation_clips(ast: DeriveInput) -> syn::Result<TokenStream> {
+    let enum_name = &ast.ident;
+    let (impl_generics, type_generics, where_clause) = ast.generics.split_for_impl();
+
+    let data_enum = match ast.data {
+        Data::Enum(ref data_enum) => data_enum,
+        Data::Struct(ref data_struct) => {
+            return Err(syn::Error::new(
+                data_struct.struct_token.span(),
+                "AnimationClips can only be derived for enums",
+            ));
+        }
+        Data::Union(ref data_union) => {
+            return Err(syn::Error::new(
+                data_union.union_token.span(),
+                "AnimationClips can only be derived for enums",
+            ));
+        }
+    };
+
+    // Parse #[trickfilm(path = "...")] on the enum
+    let mut path = None;
+    for attr in ast.attrs.iter() {
+        if attr.path().is_ident("trickfilm") {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("path") {
+                    if path.is_some() {
+                        return Err(meta.error("duplicate `path` argument"));
+                    }
+                    path = Some(meta.value()?.parse::<LitStr>()?);
+                    Ok(())
+                } else {
+                    Err(meta.error("unknown argument, expected `path`"))
+                }
+            })?;
+        }
+    }
+    let Some(path) = path else {
+        return Err(syn::Error::new(
+            enum_name.span(),
+            "missing `#[trickfilm(path = \"...\")]` attribute",
+        ));
+    };
+
+    let mut names = Vec::with_capacity(data_enum.variants.len());
+    let mut arms = Vec::with_capacity(data_enum.variants.len());
+    for variant in data_enum.variants.iter() {
+        if !matches!(variant.fields, Fields::Unit) {
+            return Err(syn::Error::new(
+                variant.fields.span(),
+                "AnimationClips variants can not have fields",
+            ));
+        }
+
+        // Parse #[clip("...")] on the variant, otherwise use the variant name in snake case
+        let mut name = None;
+        for attr in variant.attrs.iter() {
+            if attr.path().is_ident("clip") {
+                if name.is_some() {
+                    return Err(syn::Error::new(
+                        attr.meta.span(),
+                        "duplicate `clip` attribute",
+                    ));
+                }
+                name = Some(attr.parse_args::<LitStr>()?.value());
+            }
+        }
+        let name = name.unwrap_or_else(|| to_snake_case(&variant.ident.to_string()));
+
+        let variant_name = &variant.ident;
+        arms.push(quote! { Self::#variant_name => #name });
+        names.push(name);
+    }
+
+    Ok(quote! {
+        impl #impl_generics bevy_trickfilm::asset::AnimationClips for #enum_name #type_generics #where_clause {
+            const PATH: &'static str = #path;
+            const NAMES: &'static [&'static str] = &[#(#names),*];
+
+            fn name(&self) -> &'static str {
+                match self {
+                    #(#arms,)*
+                }
+            }
+        }
+    })
+}
+
+/// Convert an identifier like `IdleLeft` into `idle_left`.
+fn to_snake_case(ident: &str) -> String {
+    let mut snake_case = String::with_capacity(ident.len() + 4);
+    for (i, c) in ident.chars().enumerate() {
+        if c.is_uppercase() {
+            if i != 0 {
+                snake_case.push('_');
+            }
+            snake_case.extend(c.to_lowercase());
+        } else {
+            snake_case.push(c);
+        }
+    }
+    snake_case
+}
@@ -0,0 +1,111 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{
+    parse_quote, spanned::Spanned, Attribute, Data, DeriveInput, Fields, Ident, Index, Member,
+};
+
+pub fn derive_animation_event(mut ast: DeriveInput) -> syn::Result<TokenStream> {
+    ast.generics
+        .make_where_clause()
+        .predicates
+        .push(parse_quote! { Self: bevy::ecs::event::Event + bevy::reflect::GetTypeRegistration + bevy::reflect::FromReflect });
+
+    let type_name = &ast.ident;
+    let (impl_generics, type_generics, where_clause) = ast.generics.split_for_impl();
+
+    let set_target = match ast.data {
+        Data::Struct(ref data_struct) => {
+            find_target(&data_struct.fields)?.map(|member| quote! { self.#member = target; })
+        }
+        Data::Enum(ref data_enum) => {
+            let mut arms = Vec::with_capacity(data_enum.variants.len());
+            let mut all_targeted = true;
+            for variant in data_enum.variants.iter() {
+                match find_target(&variant.fields)? {
+                    Some(member) => {
+                        let variant_name = &variant.ident;
+                        arms.push(quote! {
+                            Self::#variant_name { #member: __target, .. } => *__target = target,
+                        });
+                    }
+                    None => all_targeted = false,
+                }
+            }
+
+            if arms.is_empty() {
+                None
+            } else {
+                // Avoid an unreachable pattern, if every variant has a target
+                let fallback = (!all_targeted).then(|| quote! { _ => {} });
+                Some(quote! {
+                    match self {
+                        #(#arms)*
+                        #fallback
+                    }
+                })
+            }
+        }
+        Data::Union(ref data_union) => {
+            return Err(syn::Error::new(
+                data_union.union_token.span(),
+                "AnimationEvent can not be derived for unions",
+            ));
+        }
+    };
+
+    Ok(match set_target {
+        Some(set_target) => quote! {
+            impl #impl_generics bevy_trickfilm::animation::event::AnimationEvent for #type_name #type_generics #where_clause {
+                fn set_target(&mut self, target: bevy_trickfilm::animation::event::EventTarget) {
+                    #set_target
+                }
+            }
+        },
+        None => quote! {
+            impl #impl_generics bevy_trickfilm::animation::event::AnimationEvent for #type_name #type_generics #where_clause {}
+        },
+    })
+}
+
+/// Find the field marked with `#[animationevent(target)]`, if any.
+fn find_target(fields: &Fields) -> syn::Result<Option<Member>> {
+    let mut target = None;
+    for (index, field) in fields.iter().enumerate() {
+        for attr in field.attrs.iter() {
+            if !is_target_attribute(attr)? {
+                continue;
+            }
+
+            if target.is_some() {
+                return Err(syn::Error::new(
+                    attr.meta.span(),
+                    "multiple `#[animationevent(target)]` attributes, only a single target is supported",
+                ));
+            }
+
+            target = Some(match field.ident {
+                Some(ref ident) => Member::Named(ident.clone()),
+                None => Member::Unnamed(Index::from(index)),
+            });
+        }
+    }
+    Ok(target)
+}
+
+/// Check if the attribute is `#[animationevent(target)]`, rejecting malformed `animationevent` attributes.
+fn is_target_attribute(attr: &Attribute) -> syn::Result<bool> {
+    if !attr.path().is_ident("animationevent") {
+        return Ok(false);
+    }
+
+    let arg = attr
+        .parse_args::<Ident>()
+        .map_err(|_| syn::Error::new(attr.meta.span(), "expected `#[animationevent(target)]`"))?;
+    if arg != format_ident!("target") {
+        return Err(syn::Error::new(
+            arg.span(),
+            format!("unknown argument `{arg}`, expected `target`"),
+        ));
+    }
+    Ok(true)
+}
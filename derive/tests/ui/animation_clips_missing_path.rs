@@ -0,0 +1,9 @@
+use bevy_trickfilm_derive::AnimationClips;
+
+#[derive(AnimationClips)]
+enum Gabe {
+    Idle,
+    Run,
+}
+
+fn main() {}
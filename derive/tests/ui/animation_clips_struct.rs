@@ -0,0 +1,7 @@
+use bevy_trickfilm_derive::AnimationClips;
+
+#[derive(AnimationClips)]
+#[trickfilm(path = "gabe.trickfilm.ron")]
+struct Gabe;
+
+fn main() {}
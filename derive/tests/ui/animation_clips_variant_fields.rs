@@ -0,0 +1,10 @@
+use bevy_trickfilm_derive::AnimationClips;
+
+#[derive(AnimationClips)]
+#[trickfilm(path = "gabe.trickfilm.ron")]
+enum Gabe {
+    Idle,
+    Run { speed: f32 },
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use bevy_trickfilm_derive::AnimationEvent;
+
+#[derive(AnimationEvent)]
+struct SampleEvent {
+    #[animationevent]
+    target: u32,
+}
+
+fn main() {}
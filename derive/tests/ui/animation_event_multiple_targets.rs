@@ -0,0 +1,9 @@
+use bevy_trickfilm_derive::AnimationEvent;
+
+#[derive(AnimationEvent)]
+enum SampleEvent {
+    Footstep,
+    Hit(#[animationevent(target)] u32, #[animationevent(target)] u32),
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use bevy_trickfilm_derive::AnimationEvent;
+
+#[derive(AnimationEvent)]
+union SampleEvent {
+    a: u32,
+    b: f32,
+}
+
+fn main() {}
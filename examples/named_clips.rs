@@ -0,0 +1,63 @@
+//! Renders an animated sprite, whose animation clips are played by their name
+//! in the animation clip set loaded from the trickfilm file.
+
+use bevy::prelude::*;
+use bevy_trickfilm::prelude::*;
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins.set(ImagePlugin::default_nearest())) // prevents blurry sprites
+        .add_plugins(Animation2DPlugin)
+        .add_systems(Startup, setup)
+        .add_systems(Update, switch_animation)
+        .run();
+}
+
+fn setup(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    mut texture_atlas_layouts: ResMut<Assets<TextureAtlasLayout>>,
+) {
+    println!("Animation controls:");
+    println!("  - return: switch between idle and run");
+
+    let atlas_texture = asset_server.load("gabe-idle-run.png");
+    let texture_atlas_layout = TextureAtlasLayout::from_grid(UVec2::new(24, 24), 7, 1, None, None);
+    let texture_atlas = TextureAtlas {
+        layout: texture_atlas_layouts.add(texture_atlas_layout),
+        ..Default::default()
+    };
+
+    // Camera
+    commands.spawn(Camera2d);
+
+    // Prepare AnimationPlayer, the name is resolved once the animation clip set is loaded
+    let mut animation_player = AnimationPlayer2D::default();
+    animation_player.play_named("run").repeat();
+
+    // SpriteSheet entity
+    commands.spawn((
+        Sprite {
+            image: atlas_texture,
+            texture_atlas: Some(texture_atlas),
+            ..Default::default()
+        },
+        Transform::from_scale(Vec3::splat(6.0)),
+        // Keeps all animation clips of the set loaded
+        AnimationClip2DSetHandle(asset_server.load("gabe-idle-run-animation.trickfilm.ron")),
+        animation_player,
+    ));
+}
+
+fn switch_animation(
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    mut animation_player: Single<&mut AnimationPlayer2D>,
+) {
+    if keyboard_input.just_pressed(KeyCode::Enter) {
+        let name = match animation_player.animation_name() {
+            Some("run") => "idle",
+            _ => "run",
+        };
+        animation_player.play_named(name).repeat();
+    }
+}
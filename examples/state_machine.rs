@@ -0,0 +1,58 @@
+//! Renders an animated sprite, whose animation clips are chosen by an animation state machine
+//! loaded from a file.
+
+use bevy::prelude::*;
+use bevy_trickfilm::prelude::*;
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins.set(ImagePlugin::default_nearest())) // prevents blurry sprites
+        .add_plugins(Animation2DPlugin)
+        .add_systems(Startup, setup)
+        .add_systems(Update, update_speed)
+        .run();
+}
+
+fn setup(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    mut texture_atlas_layouts: ResMut<Assets<TextureAtlasLayout>>,
+) {
+    println!("Animation controls:");
+    println!("  - hold arrow right: run");
+
+    let atlas_texture = asset_server.load("gabe-idle-run.png");
+    let texture_atlas_layout = TextureAtlasLayout::from_grid(UVec2::new(24, 24), 7, 1, None, None);
+    let texture_atlas = TextureAtlas {
+        layout: texture_atlas_layouts.add(texture_atlas_layout),
+        ..Default::default()
+    };
+
+    // Camera
+    commands.spawn(Camera2d);
+
+    // SpriteSheet entity
+    commands.spawn((
+        Sprite {
+            image: atlas_texture,
+            texture_atlas: Some(texture_atlas),
+            ..Default::default()
+        },
+        Transform::from_scale(Vec3::splat(6.0)),
+        AnimationPlayer2D::default(),
+        // The state machine starts in its initial state and plays the animation clip of the current state
+        AnimationController2D::new(asset_server.load("gabe-idle-run-animation.fsm.ron")),
+    ));
+}
+
+fn update_speed(
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    mut animation_controller: Single<&mut AnimationController2D>,
+) {
+    let speed = if keyboard_input.pressed(KeyCode::ArrowRight) {
+        1.0
+    } else {
+        0.0
+    };
+    animation_controller.set_float("speed", speed);
+}
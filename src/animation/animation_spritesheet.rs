@@ -6,7 +6,10 @@ use bevy::{
 
 use crate::asset::AnimationClip2D;
 
-use super::{AnimationPlayer2D, FrameIndexAnimatable, PlayingAnimation2D};
+use super::{
+    AnimationClip2DFallback, AnimationDrive, AnimationPlayer2D, AnimationTempo, ClipState,
+    FinishBehaviour, FrameIndexAnimatable, PendingPolicy, SeekEvents,
+};
 
 /// System that will play all spritesheet animations, using any entity with an [`AnimationPlayer2D`]
 /// and a [`Handle<AnimationClip2D>`] as an animation root.
@@ -16,10 +19,19 @@ pub(crate) fn animation_player_spritesheet<
 >(
     time: Res<Time<T>>,
     animation_clips: Res<Assets<AnimationClip2D>>,
+    fallback: Res<AnimationClip2DFallback>,
+    tempo: Res<AnimationTempo>,
     mut query: Query<(&mut AnimationPlayer2D<T>, &mut C)>,
 ) {
     query.par_iter_mut().for_each(|(player, sprite)| {
-        run_animation_player_spritesheet(&time, &animation_clips, player, sprite);
+        run_animation_player_spritesheet(
+            &time,
+            &animation_clips,
+            &fallback,
+            &tempo,
+            player,
+            sprite,
+        );
     });
 }
 
@@ -29,16 +41,21 @@ fn run_animation_player_spritesheet<
 >(
     time: &Time<T>,
     animation_clips: &Assets<AnimationClip2D>,
+    fallback: &AnimationClip2DFallback,
+    tempo: &AnimationTempo,
     mut player: Mut<AnimationPlayer2D<T>>,
     mut sprite: Mut<C>,
 ) {
-    if let Some(animation_clip) = animation_clips.get(&player.animation.animation_clip) {
+    if let Some(animation_clip) = player
+        .animation
+        .playable_clip(fallback)
+        .and_then(|handle| animation_clips.get(handle))
+    {
         player.animation.duration = Some(animation_clip.duration());
     }
 
     // Allow manual update of elapsed when paused
-    let paused = player.paused;
-    if paused && !player.is_changed() {
+    if player.halted() && !player.is_changed() {
         return;
     }
 
@@ -46,8 +63,9 @@ fn run_animation_player_spritesheet<
         apply_animation_player_spritesheet(
             time,
             animation_clips,
-            &mut player.animation,
-            paused,
+            fallback,
+            tempo,
+            &mut player,
             index,
         );
     }
@@ -56,34 +74,87 @@ fn run_animation_player_spritesheet<
 fn apply_animation_player_spritesheet<T: Default + Send + Sync + 'static>(
     time: &Time<T>,
     animation_clips: &Assets<AnimationClip2D>,
-    animation: &mut PlayingAnimation2D,
-    paused: bool,
+    fallback: &AnimationClip2DFallback,
+    tempo: &AnimationTempo,
+    player: &mut AnimationPlayer2D<T>,
     texture_atlas_index: &mut usize,
 ) {
-    if let Some(animation_clip) = animation_clips.get(&animation.animation_clip) {
-        // We don't return early because seek_to() may have been called on the animation player.
-        animation.update(
-            if paused { 0.0 } else { time.delta_secs() },
-            animation_clip.duration(),
-        );
+    let drive = player.drive;
+    let paused = player.halted();
+    let group_speed = player.group.speed;
+    let pending_policy = player.pending_policy;
+    let animation = &mut player.animation;
 
-        let index = match animation_clip
-            .keyframe_timestamps()
-            .binary_search_by(|probe| {
-                probe
-                    .partial_cmp(&animation.seek_time)
-                    .expect("Keyframe timestamps contain elements, that are not comparable.")
-            }) {
-            Ok(n) if n >= animation_clip.keyframe_timestamps().len() - 1 => return,
-            Ok(i) => i,
-            Err(0) => return, // this clip isn't started yet
-            Err(n) if n > animation_clip.keyframe_timestamps().len() => return,
-            Err(i) => i - 1,
+    // We don't return early when paused because seek_to() may have been called on the animation player.
+    let mut delta = if paused {
+        0.0
+    } else {
+        time.delta_secs() * group_speed
+    };
+    let mut switched = false;
+    let animation_clip = loop {
+        let Some(animation_clip) = animation
+            .playable_clip(fallback)
+            .and_then(|handle| animation_clips.get(handle))
+        else {
+            if switched && pending_policy == PendingPolicy::Accumulate {
+                // The seek_time is wrapped into the clip duration with the first update once the clip is ready
+                animation.elapsed += delta;
+                animation.seek_time += delta * animation.speed;
+            }
+            return;
         };
+        animation.duration = Some(animation_clip.duration());
+        if switched && animation.state == ClipState::Pending {
+            // An animation clip switched to, that is loaded already, is playable right away
+            animation.state = ClipState::Ready;
+        }
+
+        // Manually driven animations jump to their position, crossing all frames in between
+        if let Some(seek_time) = drive.seek_time(animation_clip, tempo) {
+            let forward = matches!(drive, AnimationDrive::Tempo(_))
+                || animation
+                    .frame
+                    .is_none_or(|frame| frame <= animation_clip.frame_at(seek_time));
+            animation.jump_to(seek_time, forward, animation_clip, SeekEvents::Fire);
+            break animation_clip;
+        }
 
-        animation.last_frame = animation.frame;
-        animation.frame = Some(index);
-        let keyframes = animation_clip.keyframes();
-        *texture_atlas_index = keyframes.get(index).expect("index is constructed from keyframe_timestamps which ensures that the operation always succeeds.");
+        // Continue with the time left over in the animation clip switched to
+        match animation.update(delta, animation_clip) {
+            Some(leftover) => {
+                delta = leftover;
+                switched = true;
+            }
+            None => break animation_clip,
+        }
+    };
+
+    let reset = animation.just_finished()
+        && player.resolve_finish_behaviour(Some(animation_clip)) == FinishBehaviour::Reset;
+    let animation = &mut player.animation;
+    if reset {
+        animation.seek_time = 0.0;
     }
+
+    // The displayed frame follows the time curve of the animation clip
+    let sample_time = animation_clip.sample_time(animation.seek_time);
+    let index = match animation_clip
+        .keyframe_timestamps()
+        .binary_search_by(|probe| {
+            probe
+                .partial_cmp(&sample_time)
+                .expect("Keyframe timestamps contain elements, that are not comparable.")
+        }) {
+        Ok(i) => i,
+        Err(0) => return, // this clip isn't started yet
+        Err(n) if n > animation_clip.keyframe_timestamps().len() => return,
+        Err(i) => i - 1,
+    };
+
+    // The first frame was reached already, so its events are not sent again after a reset
+    animation.last_frame = if reset { Some(index) } else { animation.frame };
+    animation.frame = Some(index);
+    let keyframes = animation_clip.keyframes();
+    *texture_atlas_index = keyframes.get(index).expect("index is constructed from keyframe_timestamps which ensures that the operation always succeeds.");
 }
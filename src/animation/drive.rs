@@ -0,0 +1,67 @@
+//! This module allows to drive the position of an animation by an external value instead of time.
+//!
+
+use bevy::reflect::Reflect;
+
+use crate::asset::AnimationClip2D;
+
+use super::{AnimationPlayer2D, AnimationTempo};
+
+/// What drives the position of the animation of an [`AnimationPlayer2D`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Reflect)]
+pub enum AnimationDrive {
+    /// The animation advances with [`Time`](bevy::prelude::Time).
+    #[default]
+    Time,
+    /// The animation is at the normalized time, clamped to the range [0.0, 1.0].
+    ///
+    /// E.g. the fill level of a health bar.
+    Normalized(f32),
+    /// The animation is at the seek time in seconds, clamped to the duration of the animation clip.
+    Seconds(f32),
+    /// The animation is locked to the beats of the [`AnimationTempo`], shifted by the given phase offset in beats.
+    ///
+    /// A cycle of the animation clip lasts its [`beats`](AnimationClip2D::beats) and starts on each multiple of them.
+    Tempo(f32),
+}
+
+impl AnimationDrive {
+    /// Seek time of a manually driven animation, or `None` if it advances with time.
+    pub(crate) fn seek_time(
+        self,
+        animation_clip: &AnimationClip2D,
+        tempo: &AnimationTempo,
+    ) -> Option<f32> {
+        let clip_duration = animation_clip.duration();
+        match self {
+            AnimationDrive::Time => None,
+            AnimationDrive::Normalized(t) => Some(t.clamp(0.0, 1.0) * clip_duration),
+            AnimationDrive::Seconds(seconds) => Some(seconds.clamp(0.0, clip_duration)),
+            AnimationDrive::Tempo(offset) => {
+                // Clips not authored in beats last one bar
+                let beats = animation_clip
+                    .beats()
+                    .unwrap_or(tempo.time_signature().0 as f32);
+                let phase = (tempo.beat() - offset).rem_euclid(beats) / beats;
+                Some(phase * clip_duration)
+            }
+        }
+    }
+}
+
+impl<T: Default> AnimationPlayer2D<T> {
+    /// Set what drives the position of the animation.
+    ///
+    /// A manually driven animation ignores time, speed and repetition behaviour.
+    /// The animation events of all frames crossed by a change of the value are sent, in either direction.
+    /// Animations locked to the tempo always cross frames forward, wrapping around at the end of each cycle.
+    pub fn set_drive(&mut self, drive: AnimationDrive) -> &mut Self {
+        self.drive = drive;
+        self
+    }
+
+    /// What drives the position of the animation.
+    pub fn drive(&self) -> AnimationDrive {
+        self.drive
+    }
+}
@@ -2,17 +2,26 @@
 //!
 
 use bevy::{
-    app::Animation, platform::collections::HashMap, prelude::*, reflect::GetTypeRegistration,
+    app::Animation,
+    asset::AssetEvents,
+    ecs::{archetype::Archetypes, component::Components, entity::Entities},
+    platform::collections::HashMap,
+    prelude::*,
+    reflect::GetTypeRegistration,
 };
 
-use crate::asset::AnimationClip2D;
+use crate::{asset::AnimationClip2D, rng::SplitMix64};
 
-use super::AnimationPlayer2D;
+use super::{AnimationPlayer2D, PlayingAnimation2D};
 
 /// SystemSet to order animation playing and animation events
 #[derive(Debug, Default, Hash, PartialEq, Eq, Clone, SystemSet)]
 pub(crate) struct AnimationEventSystemSet;
 
+/// SystemSet to check the conditions of animation events of the players of all time types, before any animation events are sent
+#[derive(Debug, Default, Hash, PartialEq, Eq, Clone, SystemSet)]
+pub(crate) struct AnimationEventConditionSystemSet;
+
 /// AnimationEvents are triggered by the animation system if registered as such with the App
 pub trait AnimationEvent: Event + GetTypeRegistration + FromReflect + Clone {
     /// Implement this to be able to set the entity for a targeted event.
@@ -33,8 +42,17 @@ impl Default for EventTarget {
     }
 }
 
+#[derive(Debug)]
+struct CachedAnimationEvent<T> {
+    event: T,
+    /// Index into the events of the frame, which identifies the condition of the event.
+    index: usize,
+}
+
 #[derive(Debug, Resource)]
-struct AnimationEventCache<T>(HashMap<AssetId<AnimationClip2D>, HashMap<usize, Vec<T>>>);
+struct AnimationEventCache<T>(
+    HashMap<AssetId<AnimationClip2D>, HashMap<usize, Vec<CachedAnimationEvent<T>>>>,
+);
 
 impl<T> Default for AnimationEventCache<T> {
     fn default() -> Self {
@@ -42,6 +60,93 @@ impl<T> Default for AnimationEventCache<T> {
     }
 }
 
+impl PlayingAnimation2D {
+    /// Frames whose animation events are sent with the direction they were reached in.
+    ///
+    /// Frames crossed by seeking come before the frame reached by playing.
+    fn reached_frames(&self) -> impl Iterator<Item = (usize, bool)> + '_ {
+        let reached_frame = (self.last_frame != self.frame)
+            .then(|| (self.frame.unwrap_or_default(), self.speed >= 0.0));
+        self.crossed_frames.iter().copied().chain(reached_frame)
+    }
+}
+
+impl<T: Default> AnimationPlayer2D<T> {
+    /// Set the seed for rolling the probabilities of conditional animation events.
+    ///
+    /// Otherwise the probabilities are rolled with the entity as seed.
+    pub fn set_event_seed(&mut self, seed: u64) -> &mut Self {
+        self.event_rng = Some(SplitMix64::new(seed));
+        self
+    }
+}
+
+/// System that checks the conditions of the animation events of all reached frames, before any animation events are sent.
+///
+/// The probabilities are rolled once per player and event, no matter how many animation event types are registered.
+pub(crate) fn check_animation_event_conditions<T: Default + Send + Sync + 'static>(
+    animation_clips: Res<Assets<AnimationClip2D>>,
+    type_registry: Res<AppTypeRegistry>,
+    components: &Components,
+    archetypes: &Archetypes,
+    entities: &Entities,
+    mut query: Query<(Entity, &mut AnimationPlayer2D<T>)>,
+) {
+    let type_registry = type_registry.read();
+    for (entity, mut player) in &mut query {
+        // Events suppressed on the last update must not suppress events of another or reloaded animation clip
+        if !player.animation.suppressed_events.is_empty() {
+            player
+                .bypass_change_detection()
+                .animation
+                .suppressed_events
+                .clear();
+        }
+        let Some(animation_clip) = animation_clips.get(&player.animation.animation_clip) else {
+            continue;
+        };
+        if animation_clip.event_conditions().is_empty() {
+            continue;
+        }
+
+        // The marker components are looked up in the archetype, so other components of the entity can be written in parallel
+        let archetype = entities
+            .get(entity)
+            .and_then(|location| archetypes.get(location.archetype_id));
+        let has_marker = |type_path: &String| {
+            type_registry
+                .get_with_type_path(type_path)
+                .and_then(|registration| components.get_id(registration.type_id()))
+                .zip(archetype)
+                .is_some_and(|(component_id, archetype)| archetype.contains(component_id))
+        };
+
+        let AnimationPlayer2D {
+            animation,
+            event_rng,
+            ..
+        } = player.bypass_change_detection();
+        let rng = event_rng.get_or_insert_with(|| SplitMix64::new(entity.to_bits()));
+        let mut suppressed_events = Vec::new();
+        for (position, (frame, forward)) in animation.reached_frames().enumerate() {
+            let events = animation_clip.events().get(&frame).map_or(0, Vec::len);
+            for index in 0..events {
+                let Some(condition) = animation_clip.event_condition(frame, index) else {
+                    continue;
+                };
+                // The probability is rolled last, so the random numbers only depend on the events that could be sent
+                let met = condition.matches(animation.completions, forward)
+                    && condition.marker.as_ref().is_none_or(has_marker)
+                    && (condition.probability >= 1.0 || rng.next_f32() < condition.probability);
+                if !met {
+                    suppressed_events.push((position, index));
+                }
+            }
+        }
+        animation.suppressed_events = suppressed_events;
+    }
+}
+
 // This updates a cache resource for each Event added to the app
 // That way when processing animation for event sending, we already have a vector of T instead of Box<dyn Reflect>, so we only iterate through the events that are actually relevant (can be from_reflected to T)
 fn update_animation_event_cache<T: FromReflect>(
@@ -63,7 +168,13 @@ fn update_animation_event_cache<T: FromReflect>(
                                 *frame,
                                 events
                                     .iter()
-                                    .filter_map(|event| T::from_reflect(event.as_partial_reflect()))
+                                    .enumerate()
+                                    .filter_map(|(index, event)| {
+                                        Some(CachedAnimationEvent {
+                                            event: T::from_reflect(event.as_partial_reflect())?,
+                                            index,
+                                        })
+                                    })
                                     .collect(),
                             )
                         })
@@ -83,7 +194,7 @@ fn update_animation_event_cache<T: FromReflect>(
     }
 }
 
-// Collects events in a vector per entity for batching purposes
+// Collects events in a vector per entity for batching purposes, unless their conditions are not met
 // Also calls AnimationEvent's set_target
 fn collect_events<T: AnimationEvent>(
     animation_players: Query<(Entity, &AnimationPlayer2D)>,
@@ -94,14 +205,25 @@ fn collect_events<T: AnimationEvent>(
         .map(|(entity, animation_player)| {
             let mut events: Vec<T> = Vec::with_capacity(0);
             if let Some(event_map) = cache.0.get(&animation_player.animation_clip().id()) {
-                if animation_player.animation.last_frame != animation_player.animation.frame {
-                    if let Some(animation_events) = event_map.get(&animation_player.frame()) {
-                        events = animation_events.clone();
-                        events
-                            .iter_mut()
-                            .for_each(|event| event.set_target(EventTarget(entity)));
-                    }
+                let animation = &animation_player.animation;
+                for (position, (frame, _)) in animation.reached_frames().enumerate() {
+                    let Some(cached_events) = event_map.get(&frame) else {
+                        continue;
+                    };
+                    events.extend(
+                        cached_events
+                            .iter()
+                            .filter(|cached| {
+                                !animation
+                                    .suppressed_events
+                                    .contains(&(position, cached.index))
+                            })
+                            .map(|cached| cached.event.clone()),
+                    );
                 }
+                events
+                    .iter_mut()
+                    .for_each(|event| event.set_target(EventTarget(entity)));
             }
             (entity, events)
         })
@@ -153,11 +275,13 @@ fn add_animation_cache<T: AnimationEvent>(app: &mut App) {
         .is_none()
     {
         app.init_resource::<AnimationEventCache<T>>();
+        // Modified animation clips are cached in the same frame, before their events are collected
         app.add_systems(
             PostUpdate,
             update_animation_event_cache::<T>
                 .in_set(Animation)
-                .in_set(AnimationEventSystemSet),
+                .in_set(AnimationEventSystemSet)
+                .after(AssetEvents),
         );
     }
 
@@ -174,7 +298,8 @@ impl AnimationEventAppExtension for App {
             send_animation_event::<T>
                 .in_set(Animation)
                 .in_set(AnimationEventSystemSet)
-                .after(update_animation_event_cache::<T>),
+                .after(update_animation_event_cache::<T>)
+                .after(AnimationEventConditionSystemSet),
         )
     }
 
@@ -187,7 +312,8 @@ impl AnimationEventAppExtension for App {
             trigger_animation_event::<T>
                 .in_set(Animation)
                 .in_set(AnimationEventSystemSet)
-                .after(update_animation_event_cache::<T>),
+                .after(update_animation_event_cache::<T>)
+                .after(AnimationEventConditionSystemSet),
         )
     }
 }
@@ -0,0 +1,96 @@
+//! This module handles what happens once an animation finished.
+//!
+
+use bevy::prelude::{Assets, Commands, DetectChangesMut, Entity, Query, Reflect, Res, Visibility};
+use serde::Deserialize;
+
+use crate::asset::AnimationClip2D;
+
+use super::{AnimationClip2DFallback, AnimationPlayer2D};
+
+/// What happens once an animation finished, according to its repetition behaviour.
+///
+/// Nothing happens if another animation clip is played next, e.g. from the queue or the [`RestingAnimation2D`](super::RestingAnimation2D).
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Deserialize, Reflect)]
+pub enum FinishBehaviour {
+    /// Hold the last frame.
+    #[default]
+    Hold,
+    /// Reset to the first frame.
+    Reset,
+    /// Hide the entity.
+    Hide,
+    /// Remove the [`AnimationPlayer2D`] from the entity.
+    RemovePlayer,
+    /// Despawn the entity and its descendants.
+    Despawn,
+}
+
+impl<T: Default> AnimationPlayer2D<T> {
+    /// Set what happens once an animation finished, overriding the finish behaviour of the animation clips.
+    pub fn set_finish_behaviour(&mut self, finish_behaviour: FinishBehaviour) -> &mut Self {
+        self.finish_behaviour = Some(finish_behaviour);
+        self
+    }
+
+    /// Use the finish behaviour of the animation clips again.
+    pub fn clear_finish_behaviour(&mut self) -> &mut Self {
+        self.finish_behaviour = None;
+        self
+    }
+
+    /// Finish behaviour set on this player, overriding the finish behaviour of the animation clips.
+    pub fn finish_behaviour(&self) -> Option<FinishBehaviour> {
+        self.finish_behaviour
+    }
+
+    /// What happens once the playing animation finished.
+    pub(crate) fn resolve_finish_behaviour(
+        &self,
+        animation_clip: Option<&AnimationClip2D>,
+    ) -> FinishBehaviour {
+        self.finish_behaviour
+            .or_else(|| animation_clip.and_then(AnimationClip2D::finish_behaviour))
+            .unwrap_or_default()
+    }
+}
+
+/// System that hides, removes the player of or despawns entities whose animation just finished.
+pub(crate) fn finish_animations<T: Default + Send + Sync + 'static>(
+    mut commands: Commands,
+    animation_clips: Res<Assets<AnimationClip2D>>,
+    fallback: Res<AnimationClip2DFallback>,
+    mut query: Query<(Entity, &mut AnimationPlayer2D<T>)>,
+) {
+    for (entity, mut player) in &mut query {
+        // A player that is not updated, e.g. while paused, stays just finished,
+        // so the finish behaviour is only applied once it becomes finished
+        if !player.just_finished() {
+            if player.animation.finish_applied {
+                player.bypass_change_detection().animation.finish_applied = false;
+            }
+            continue;
+        }
+        if player.animation.finish_applied {
+            continue;
+        }
+        player.bypass_change_detection().animation.finish_applied = true;
+
+        let animation_clip = player
+            .animation
+            .playable_clip(&fallback)
+            .and_then(|handle| animation_clips.get(handle));
+        match player.resolve_finish_behaviour(animation_clip) {
+            FinishBehaviour::Hold | FinishBehaviour::Reset => {}
+            FinishBehaviour::Hide => {
+                commands.entity(entity).insert(Visibility::Hidden);
+            }
+            FinishBehaviour::RemovePlayer => {
+                commands.entity(entity).remove::<AnimationPlayer2D<T>>();
+            }
+            FinishBehaviour::Despawn => {
+                commands.entity(entity).despawn();
+            }
+        }
+    }
+}
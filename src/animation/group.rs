@@ -0,0 +1,140 @@
+//! This module allows to pause, resume and scale the speed of named groups of animation players at once.
+//!
+
+use bevy::{
+    platform::collections::HashMap,
+    prelude::{
+        ChildOf, Component, Entity, Query, Reflect, ReflectComponent, ReflectResource, Res,
+        Resource,
+    },
+};
+
+use super::AnimationPlayer2D;
+
+/// Tags the [`AnimationPlayer2D`] of the entity as part of the named animation group.
+///
+/// Groups are controlled with the [`AnimationGroups`] resource.
+#[derive(Component, Debug, Clone, PartialEq, Eq, Hash, Reflect)]
+#[reflect(Component)]
+pub struct AnimationGroup2D {
+    name: String,
+    propagate: bool,
+}
+
+impl AnimationGroup2D {
+    /// Creates a new AnimationGroup2D, that does not propagate to the children of the entity.
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            propagate: false,
+        }
+    }
+
+    /// Set if the animation players of all descendants without their own group are part of this group as well.
+    pub fn with_propagate(mut self, propagate: bool) -> Self {
+        self.propagate = propagate;
+        self
+    }
+
+    /// Name of the animation group.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Check if the group propagates to the descendants of the entity.
+    pub fn propagate(&self) -> bool {
+        self.propagate
+    }
+}
+
+/// Playback state of an animation group.
+#[derive(Debug, Clone, Copy, PartialEq, Reflect)]
+pub(crate) struct AnimationGroupPlayback {
+    pub(crate) paused: bool,
+    pub(crate) speed: f32,
+}
+
+impl Default for AnimationGroupPlayback {
+    fn default() -> Self {
+        Self {
+            paused: false,
+            speed: 1.0,
+        }
+    }
+}
+
+/// Pause, resume and speed of all animation groups.
+///
+/// The speed of a group is combined multiplicatively with the speed of the animations of its players.
+#[derive(Debug, Default, Clone, Resource, Reflect)]
+#[reflect(Resource)]
+pub struct AnimationGroups {
+    groups: HashMap<String, AnimationGroupPlayback>,
+}
+
+impl AnimationGroups {
+    /// Pause all animation players of the group.
+    pub fn pause(&mut self, group: impl Into<String>) -> &mut Self {
+        self.groups.entry(group.into()).or_default().paused = true;
+        self
+    }
+
+    /// Resume all animation players of the group.
+    ///
+    /// Animation players that were paused on their own stay paused.
+    pub fn resume(&mut self, group: impl Into<String>) -> &mut Self {
+        self.groups.entry(group.into()).or_default().paused = false;
+        self
+    }
+
+    /// Set the speed of all animation players of the group.
+    pub fn set_speed(&mut self, group: impl Into<String>, speed: f32) -> &mut Self {
+        self.groups.entry(group.into()).or_default().speed = speed;
+        self
+    }
+
+    /// Check if the group is paused.
+    pub fn paused(&self, group: &str) -> bool {
+        self.playback(group).paused
+    }
+
+    /// Speed of the group.
+    pub fn speed(&self, group: &str) -> f32 {
+        self.playback(group).speed
+    }
+
+    fn playback(&self, group: &str) -> AnimationGroupPlayback {
+        self.groups.get(group).copied().unwrap_or_default()
+    }
+}
+
+impl<T: Default> AnimationPlayer2D<T> {
+    /// Check if the player is paused, either on its own or by its animation group.
+    pub(crate) fn halted(&self) -> bool {
+        self.paused || self.group.paused
+    }
+}
+
+/// System that applies the playback state of the animation groups to their animation players.
+pub(crate) fn update_animation_groups<T: Default + Send + Sync + 'static>(
+    groups: Res<AnimationGroups>,
+    group_query: Query<&AnimationGroup2D>,
+    parent_query: Query<&ChildOf>,
+    mut query: Query<(Entity, &mut AnimationPlayer2D<T>)>,
+) {
+    for (entity, mut player) in &mut query {
+        // Players without their own group are part of the group of the nearest ancestor propagating it
+        let group = group_query.get(entity).ok().or_else(|| {
+            parent_query
+                .iter_ancestors(entity)
+                .filter_map(|ancestor| group_query.get(ancestor).ok())
+                .find(|group| group.propagate)
+        });
+        let playback = group
+            .map(|group| groups.playback(&group.name))
+            .unwrap_or_default();
+        if player.group != playback {
+            player.group = playback;
+        }
+    }
+}
@@ -0,0 +1,101 @@
+//! This module keeps the playback state of animation players consistent, when their animation clip is hot reloaded.
+//!
+
+use bevy::{
+    platform::collections::HashMap,
+    prelude::{AssetEvent, AssetId, Assets, EventReader, Local, Query, Res, Resource},
+    reflect::Reflect,
+};
+
+use crate::asset::AnimationClip2D;
+
+use super::{AnimationPlayer2D, PlayingAnimation2D};
+
+/// How the position of playing animations is mapped into a hot reloaded animation clip.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Resource, Reflect)]
+pub enum AnimationReloadMode {
+    /// Keep the position relative to the duration of the animation clip.
+    #[default]
+    Proportional,
+    /// Keep the current frame and continue from its start.
+    Frame,
+}
+
+impl PlayingAnimation2D {
+    /// Map the position of this animation into the reloaded animation clip.
+    ///
+    /// The frame is updated right away, so no animation events are sent for the remapped frame.
+    fn remap(
+        &mut self,
+        animation_clip: &AnimationClip2D,
+        old_duration: f32,
+        mode: AnimationReloadMode,
+    ) {
+        if self.duration.is_none() {
+            // Nothing was played yet
+            return;
+        }
+        let duration = animation_clip.duration();
+
+        self.seek_time = match (mode, self.frame) {
+            (AnimationReloadMode::Frame, Some(frame)) => {
+                let frames = animation_clip.keyframe_timestamps().len();
+                animation_clip.frame_start(frame.min(frames - 1))
+            }
+            _ => self.seek_time / old_duration * duration,
+        }
+        .clamp(0.0, duration);
+        self.duration = Some(duration);
+
+        let frame = animation_clip.frame_at(self.seek_time);
+        self.last_frame = Some(frame);
+        self.frame = Some(frame);
+    }
+}
+
+/// System that remaps all animation players, whose animation clip was modified.
+///
+/// The duration of each animation clip is remembered, because the players already see the reloaded animation clip.
+pub(crate) fn remap_reloaded_animations<T: Default + Send + Sync + 'static>(
+    mode: Res<AnimationReloadMode>,
+    mut durations: Local<HashMap<AssetId<AnimationClip2D>, f32>>,
+    mut asset_events: EventReader<AssetEvent<AnimationClip2D>>,
+    animation_clips: Res<Assets<AnimationClip2D>>,
+    mut query: Query<&mut AnimationPlayer2D<T>>,
+) {
+    let mut modified = HashMap::new();
+    for asset_event in asset_events.read() {
+        match asset_event {
+            AssetEvent::Added { id }
+            | AssetEvent::Modified { id }
+            | AssetEvent::LoadedWithDependencies { id } => {
+                let Some(animation_clip) = animation_clips.get(*id) else {
+                    continue;
+                };
+                if let Some(old_duration) = durations.insert(*id, animation_clip.duration())
+                    && matches!(asset_event, AssetEvent::Modified { .. })
+                {
+                    // The duration before the first modification of this update
+                    modified.entry(*id).or_insert(old_duration);
+                }
+            }
+            AssetEvent::Removed { id } | AssetEvent::Unused { id } => {
+                durations.remove(id);
+            }
+        }
+    }
+    if modified.is_empty() {
+        return;
+    }
+
+    for mut player in &mut query {
+        let id = player.animation.animation_clip.id();
+        let Some(old_duration) = modified.get(&id) else {
+            continue;
+        };
+
+        if let Some(animation_clip) = animation_clips.get(id) {
+            player.animation.remap(animation_clip, *old_duration, *mode);
+        }
+    }
+}
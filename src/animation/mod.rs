@@ -2,24 +2,91 @@
 //!
 
 mod animation_spritesheet;
+mod drive;
 pub mod event;
-
-use std::marker::PhantomData;
-
-use crate::prelude::AnimationClip2D;
+mod finish;
+mod group;
+mod hot_reload;
+mod named;
+mod pending;
+mod queue;
+mod request;
+mod resting;
+mod scene;
+mod snapshot;
+mod state_machine;
+mod switch;
+mod sync;
+mod sync_group;
+mod tempo;
+mod transport;
+mod variation;
+
+use std::{collections::VecDeque, marker::PhantomData};
+
+use crate::{
+    prelude::{AnimationClip2D, AnimationClip2DSet},
+    rng::SplitMix64,
+};
 use bevy::{
     animation::RepeatAnimation,
     app::{Animation, PostUpdate},
-    ecs::{component::Mutable, schedule::IntoScheduleConfigs},
+    asset::{AssetEvents, AssetId, AssetPath},
+    ecs::{
+        component::Mutable,
+        schedule::{IntoScheduleConfigs, SystemSet},
+    },
     prelude::{App, Component, Handle, ImageNode, Plugin, ReflectComponent},
     reflect::{Reflect, TypePath},
     sprite::Sprite,
 };
-use event::{AnimationEventSystemSet, EventTarget};
+use event::{
+    AnimationEventConditionSystemSet, AnimationEventSystemSet, EventTarget,
+    check_animation_event_conditions,
+};
 
-use self::animation_spritesheet::animation_player_spritesheet;
+use self::{
+    animation_spritesheet::animation_player_spritesheet,
+    finish::finish_animations,
+    group::{AnimationGroupPlayback, update_animation_groups},
+    hot_reload::remap_reloaded_animations,
+    named::{loop_animation_variants, resolve_animation_names},
+    pending::update_pending_animations,
+    queue::send_queued_animation_events,
+    request::resolve_animation_requests,
+    resting::update_resting_animations,
+    scene::{load_animation_clips_from_paths, record_animation_clip_paths},
+    state_machine::run_animation_state_machines,
+    sync::{PendingSync, sync_animations},
+    sync_group::update_sync_groups,
+    transport::clear_crossed_frames,
+    variation::vary_started_animations,
+};
 
+pub use drive::AnimationDrive;
 pub use event::{AnimationEvent, AnimationEventAppExtension};
+pub use finish::FinishBehaviour;
+pub use group::{AnimationGroup2D, AnimationGroups};
+pub use hot_reload::AnimationReloadMode;
+pub use named::{AnimationClip2DNotFound, AnimationClip2DSetHandle};
+pub use pending::{
+    AnimationClip2DFallback, AnimationClip2DLoadFailed, AnimationClip2DReady, PendingPolicy,
+};
+pub use queue::{QueuedAnimation, QueuedAnimationStarted};
+pub use request::{AnimationLock, AnimationRequest};
+pub use resting::RestingAnimation2D;
+pub use snapshot::AnimationPlayer2DSnapshot;
+pub use state_machine::AnimationController2D;
+pub use switch::{PendingSwitch, SwitchPoint};
+pub use sync::SyncMode;
+pub use sync_group::{AnimationSyncClocks, AnimationSyncGroup2D};
+pub use tempo::{AnimationTempo, AnimationTempoError};
+pub use transport::SeekEvents;
+pub use variation::AnimationVariation2D;
+
+/// SystemSet to prepare animation players before animations are played.
+#[derive(Debug, Default, Hash, PartialEq, Eq, Clone, SystemSet)]
+pub(crate) struct AnimationPlayer2DPrepareSystemSet;
 
 /// Adds support for spritesheet animation playing.
 pub struct AnimationPlayer2DPlugin<T: Default = ()>(PhantomData<T>);
@@ -41,7 +108,85 @@ impl<T: Default + Send + Sync + 'static + TypePath> Plugin for AnimationPlayer2D
     fn build(&self, app: &mut App) {
         app.register_type::<AnimationPlayer2D<T>>()
             .register_type::<PlayingAnimation2D>()
-            .register_type::<EventTarget>();
+            .register_type::<AnimationPlayer2DSnapshot>()
+            .register_type::<EventTarget>()
+            .register_type::<AnimationReloadMode>()
+            .register_type::<AnimationClip2DSetHandle>()
+            .register_type::<AnimationController2D>()
+            .register_type::<SwitchPoint>()
+            .register_type::<SyncMode>()
+            .register_type::<QueuedAnimation>()
+            .register_type::<AnimationLock>()
+            .register_type::<AnimationRequest>()
+            .register_type::<RestingAnimation2D>()
+            .register_type::<FinishBehaviour>()
+            .register_type::<SeekEvents>()
+            .register_type::<AnimationDrive>()
+            .register_type::<AnimationTempo>()
+            .register_type::<AnimationGroup2D>()
+            .register_type::<AnimationGroups>()
+            .register_type::<AnimationSyncGroup2D>()
+            .register_type::<AnimationSyncClocks<T>>()
+            .register_type::<AnimationVariation2D>()
+            .init_resource::<AnimationClip2DFallback>()
+            .init_resource::<AnimationReloadMode>()
+            .init_resource::<AnimationTempo>()
+            .init_resource::<AnimationGroups>()
+            .init_resource::<AnimationSyncClocks<T>>()
+            .add_event::<AnimationClip2DReady>()
+            .add_event::<AnimationClip2DLoadFailed>()
+            .add_event::<AnimationClip2DNotFound>()
+            .add_event::<QueuedAnimationStarted>();
+        app.add_systems(
+            PostUpdate,
+            (
+                load_animation_clips_from_paths::<T>,
+                record_animation_clip_paths::<T>,
+            )
+                .chain()
+                .before(Animation),
+        );
+        app.add_systems(
+            PostUpdate,
+            run_animation_state_machines::<T>
+                .in_set(Animation)
+                .before(AnimationPlayer2DPrepareSystemSet),
+        );
+        app.add_systems(
+            PostUpdate,
+            (
+                update_animation_groups::<T>,
+                resolve_animation_requests::<T>,
+                update_resting_animations::<T>,
+                // Modified animation clips are reported in the same frame
+                remap_reloaded_animations::<T>.after(AssetEvents),
+                resolve_animation_names::<T>,
+                update_pending_animations::<T>,
+                loop_animation_variants::<T>,
+                vary_started_animations::<T>,
+                sync_animations::<T>,
+                update_sync_groups::<T>,
+            )
+                .chain()
+                .in_set(Animation)
+                .in_set(AnimationPlayer2DPrepareSystemSet),
+        );
+        app.add_systems(
+            PostUpdate,
+            (
+                send_queued_animation_events::<T>,
+                finish_animations::<T>,
+                check_animation_event_conditions::<T>.in_set(AnimationEventConditionSystemSet),
+            )
+                .in_set(Animation)
+                .in_set(AnimationEventSystemSet),
+        );
+        app.add_systems(
+            PostUpdate,
+            clear_crossed_frames::<T>
+                .in_set(Animation)
+                .after(AnimationEventSystemSet),
+        );
         app.add_plugins((
             FrameIndexAnimationPlugin::<Sprite, T>::default(),
             FrameIndexAnimationPlugin::<ImageNode, T>::default(),
@@ -70,6 +215,7 @@ impl<C: FrameIndexAnimatable + Component<Mutability = Mutable>, T: Default + Sen
             PostUpdate,
             animation_player_spritesheet::<C, T>
                 .in_set(Animation)
+                .after(AnimationPlayer2DPrepareSystemSet)
                 .before(AnimationEventSystemSet),
         );
     }
@@ -113,33 +259,110 @@ impl FrameIndexAnimatable for ImageNode {
     }
 }
 
+/// Load state of the animation clip of a [`PlayingAnimation2D`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Reflect)]
+pub(crate) enum ClipState {
+    /// The animation clip is not loaded yet.
+    #[default]
+    Pending,
+    /// The animation clip is loaded and can be played.
+    Ready,
+    /// The animation clip failed to load, the [`AnimationClip2DFallback`] is played instead.
+    Failed,
+}
+
 #[derive(Reflect, Clone)]
 pub(crate) struct PlayingAnimation2D {
     repeat: RepeatAnimation,
+    ping_pong: bool,
     speed: f32,
     elapsed: f32,
     duration: Option<f32>,
     pub(crate) last_frame: Option<usize>,
     frame: Option<usize>,
+    /// Frames crossed by seeking since the last animation events were sent, in the order they were crossed and if they were crossed forward.
+    #[reflect(ignore)]
+    pub(crate) crossed_frames: Vec<(usize, bool)>,
     seek_time: f32,
+    // Handles can not be serialized, the clip is restored from `animation_clip_path` instead.
+    #[reflect(ignore)]
     animation_clip: Handle<AnimationClip2D>,
+    animation_clip_path: Option<AssetPath<'static>>,
+    /// Set of the animation clip, held while the animation clip is not loaded to detect a missing label.
+    #[reflect(ignore)]
+    animation_clip_set: Option<Handle<AnimationClip2DSet>>,
+    animation_name: Option<String>,
     completions: u32,
     completions_this_update: u32,
+    /// Cycles already counted since the last update, e.g. by stepping frames or before switching to another variant,
+    /// which are reported by the next update.
+    #[reflect(ignore)]
+    stepped_completions: u32,
+    state: ClipState,
+    /// Restored from a snapshot of a playable animation,
+    /// so the animation clip becomes ready without an [`AnimationClip2DReady`] event once it is loaded.
+    #[reflect(ignore)]
+    pub(crate) restored: bool,
+    priority: i32,
+    lock: AnimationLock,
+    // Handles can not be serialized, so a pending switch is not stored in scenes.
+    #[reflect(ignore)]
+    pending_switch: Option<PendingSwitch>,
+    /// Next animation of the variant group being played, that is switched to once the cycle ends.
+    #[reflect(ignore)]
+    pending_variant: Option<Handle<AnimationClip2D>>,
+    #[reflect(ignore)]
+    pending_sync: Option<PendingSync>,
+    #[reflect(ignore)]
+    queue: VecDeque<QueuedAnimation>,
+    #[reflect(ignore)]
+    resting: Option<RestingAnimation2D>,
+    /// Animation clips of the queue started since the last [`QueuedAnimationStarted`] events, with the remaining queue length.
+    #[reflect(ignore)]
+    started_from_queue: Vec<(AssetId<AnimationClip2D>, usize)>,
+    /// Started from the beginning, but the [`AnimationVariation2D`] was not applied yet.
+    #[reflect(ignore)]
+    pending_variation: bool,
+    /// Conditional animation events of the reached frames, that are not sent, identified by the position of the frame and their index.
+    #[reflect(ignore)]
+    pub(crate) suppressed_events: Vec<(usize, usize)>,
+    /// The finish behaviour was applied since the animation finished.
+    #[reflect(ignore)]
+    pub(crate) finish_applied: bool,
 }
 
 impl Default for PlayingAnimation2D {
     fn default() -> Self {
         Self {
             repeat: Default::default(),
+            ping_pong: false,
             speed: 1.0,
             elapsed: 0.0,
             duration: None,
             last_frame: None,
             frame: None,
+            crossed_frames: Vec::new(),
             seek_time: 0.0,
             animation_clip: Default::default(),
+            animation_clip_path: None,
+            animation_clip_set: None,
+            animation_name: None,
             completions: 0,
             completions_this_update: 0,
+            stepped_completions: 0,
+            state: ClipState::Pending,
+            restored: false,
+            priority: 0,
+            lock: AnimationLock::None,
+            pending_switch: None,
+            pending_variant: None,
+            pending_sync: None,
+            queue: VecDeque::new(),
+            resting: None,
+            started_from_queue: Vec::new(),
+            pending_variation: false,
+            suppressed_events: Vec::new(),
+            finish_applied: false,
         }
     }
 }
@@ -176,41 +399,174 @@ impl PlayingAnimation2D {
         self.completions_this_update > 0
     }
 
-    /// Update the animation given the delta time and the duration of the clip being played.
+    /// Handle to the animation clip that is actually played.
+    ///
+    /// This is the [`AnimationClip2DFallback`], if the animation clip failed to load.
     #[inline]
-    fn update(&mut self, delta: f32, clip_duration: f32) {
-        self.completions_this_update = 0;
-        if self.finished() {
-            return;
+    fn playable_clip<'a>(
+        &'a self,
+        fallback: &'a AnimationClip2DFallback,
+    ) -> Option<&'a Handle<AnimationClip2D>> {
+        match self.state {
+            ClipState::Failed => fallback.0.as_ref(),
+            _ => Some(&self.animation_clip),
         }
+    }
 
-        self.elapsed += delta;
-        self.seek_time += delta * self.speed;
+    /// Update the animation given the delta time and the animation clip being played.
+    ///
+    /// If the switch point of the pending switch is reached or the animation finishes with a next animation clip,
+    /// the animation switches to the next animation clip and the time left over from this update is returned.
+    #[inline]
+    fn update(&mut self, delta: f32, animation_clip: &AnimationClip2D) -> Option<f32> {
+        // Cycles completed by stepping frames or before switching to another variant are already counted
+        let stepped_completions = std::mem::take(&mut self.stepped_completions);
+        self.completions_this_update = stepped_completions;
+        if self.finished() {
+            // A finished animation will never reach the switch point
+            return self
+                .start_next()
+                .map(|carry| if carry { delta } else { 0.0 });
+        }
 
-        // We determine the number of completions this update based on the seek_time and clip_duration.
-        // For negative speeds where seek_time becomes negative, we need to consider that anything below 0.0 is already a completion.
-        let quotient = (self.seek_time.abs() / clip_duration) as u32;
-        self.completions_this_update = quotient + if self.seek_time < 0.0 { 1 } else { 0 };
-        self.completions += self.completions_this_update;
+        let clip_duration = animation_clip.duration();
+
+        // Stop exactly at the switch point, if it is reached during this update.
+        let mut switch_seek_time = None;
+        let mut leftover = None;
+        let mut delta = delta;
+        // The next animation of a variant group is switched to at the end of the cycle
+        let switch_point = match (&self.pending_switch, &self.pending_variant) {
+            (Some(pending_switch), _) => Some(pending_switch.at()),
+            (None, Some(_)) => Some(SwitchPoint::EndOfCycle),
+            (None, None) => None,
+        };
+        if let Some(switch_point) = switch_point
+            && self.speed != 0.0
+        {
+            let distance = switch_point.distance(
+                self.seek_time,
+                self.speed < 0.0,
+                self.ping_pong,
+                animation_clip,
+            );
+            let time_to_switch = distance / self.speed.abs();
+            if time_to_switch <= delta {
+                switch_seek_time = Some(self.seek_time + distance.copysign(self.speed));
+                leftover = Some(delta - time_to_switch);
+                delta = time_to_switch;
+            }
+        }
 
-        // Clamp the seek_time to [0.0, clip_duration].
-        let modulo = self.seek_time.abs() % clip_duration;
-        if self.seek_time >= clip_duration {
+        let seek_time = self.seek_time;
+        let speed = self.speed;
+        let remaining_completions = match self.repeat {
+            RepeatAnimation::Never => Some(1),
+            RepeatAnimation::Count(n) => Some(n),
+            RepeatAnimation::Forever => None,
+        }
+        .map(|n| n.saturating_sub(self.completions));
+        self.elapsed += delta;
+        self.seek_time = switch_seek_time.unwrap_or(self.seek_time + delta * self.speed);
+
+        if self.ping_pong {
+            // Bounce at both ends of the clip, each bounce completes a cycle.
+            let (bounces, modulo) = if self.seek_time >= 0.0 {
+                let bounces = (self.seek_time / clip_duration) as u32;
+                let modulo = self.seek_time % clip_duration;
+                (
+                    bounces,
+                    if bounces.is_multiple_of(2) {
+                        modulo
+                    } else {
+                        clip_duration - modulo
+                    },
+                )
+            } else {
+                let bounces = (-self.seek_time / clip_duration) as u32 + 1;
+                let modulo = -self.seek_time % clip_duration;
+                (
+                    bounces,
+                    if bounces.is_multiple_of(2) {
+                        clip_duration - modulo
+                    } else {
+                        modulo
+                    },
+                )
+            };
+            self.completions_this_update += bounces;
             self.seek_time = modulo;
-        } else if self.seek_time < 0.0 {
-            self.seek_time = clip_duration - modulo;
+            if !bounces.is_multiple_of(2) {
+                self.speed = -self.speed;
+            }
+        } else {
+            // We determine the number of completions this update based on the seek_time and clip_duration.
+            // For negative speeds where seek_time becomes negative, we need to consider that anything below 0.0 is already a completion.
+            let quotient = (self.seek_time.abs() / clip_duration) as u32;
+            self.completions_this_update += quotient + if self.seek_time < 0.0 { 1 } else { 0 };
+
+            // Clamp the seek_time to [0.0, clip_duration].
+            let modulo = self.seek_time.abs() % clip_duration;
+            if self.seek_time >= clip_duration {
+                self.seek_time = modulo;
+            } else if self.seek_time < 0.0 {
+                self.seek_time = clip_duration - modulo;
+            }
         }
+        self.completions += self.completions_this_update - stepped_completions;
 
         // If the animation is finished, we might not end up at the last frame if the delta step was too big.
         // Make sure that we have the last frame in that case
-        if self.finished() {
-            self.seek_time = clip_duration;
+        if self.finished()
+            && let Some(remaining_completions) = remaining_completions
+        {
+            // The cycle that finished the animation played in this direction
+            let reversed =
+                (speed < 0.0) != (self.ping_pong && remaining_completions.is_multiple_of(2));
+            if self.ping_pong {
+                self.speed = if reversed { -speed.abs() } else { speed.abs() };
+                self.seek_time = if reversed { 0.0 } else { clip_duration };
+            } else {
+                self.seek_time = clip_duration;
+            }
+
+            // The time after the last completion is carried over to the next animation clip
+            if leftover.is_none()
+                && (self.pending_switch.is_some()
+                    || !self.queue.is_empty()
+                    || self.resting.is_some())
+            {
+                let distance = if speed < 0.0 {
+                    seek_time
+                } else {
+                    clip_duration - seek_time
+                } + remaining_completions.saturating_sub(1) as f32 * clip_duration;
+                leftover = Some((delta - distance / speed.abs()).clamp(0.0, delta));
+            }
+        }
+
+        let leftover = leftover?;
+        match self.start_next() {
+            Some(true) => Some(leftover),
+            _ => Some(0.0),
+        }
+    }
+
+    /// Start playing the animation clip of the pending switch, the next animation clip of the queue or else the resting animation clip.
+    ///
+    /// Returns if the time left over is carried over, or `None` if there is nothing to start.
+    fn start_next(&mut self) -> Option<bool> {
+        if self.switch() || self.switch_variant() || self.start_queued() {
+            Some(true)
+        } else {
+            self.start_resting()
         }
     }
 
     /// Reset back to the initial state as if no time has elapsed.
     fn replay(&mut self) {
         self.completions_this_update = 0;
+        self.stepped_completions = 0;
         self.completions = 0;
         self.elapsed = 0.0;
         self.seek_time = 0.0;
@@ -218,11 +574,30 @@ impl PlayingAnimation2D {
 }
 
 /// Animation controls
+///
+/// The player can be saved to and loaded from a scene.
+/// Its animation clip is stored by asset path and loaded again once the player is spawned.
 #[derive(Component, Reflect, Clone)]
 #[reflect(Component)]
 pub struct AnimationPlayer2D<T: Default = ()> {
     paused: bool,
+    pending_policy: PendingPolicy,
+    finish_behaviour: Option<FinishBehaviour>,
+    seek_events: SeekEvents,
+    drive: AnimationDrive,
     pub(crate) animation: PlayingAnimation2D,
+    // Requests are resolved within the same frame, so they are not stored in scenes.
+    #[reflect(ignore)]
+    requests: Vec<AnimationRequest>,
+    // The playback state of the animation group is applied every frame, so it is not stored in scenes.
+    #[reflect(ignore)]
+    group: AnimationGroupPlayback,
+    #[reflect(ignore)]
+    variant_rng: Option<SplitMix64>,
+    #[reflect(ignore)]
+    event_rng: Option<SplitMix64>,
+    #[reflect(ignore)]
+    variation_rng: Option<SplitMix64>,
     #[reflect(ignore)]
     time: PhantomData<T>,
 }
@@ -231,7 +606,16 @@ impl Default for AnimationPlayer2D<()> {
     fn default() -> Self {
         Self {
             paused: Default::default(),
+            pending_policy: Default::default(),
+            finish_behaviour: None,
+            seek_events: Default::default(),
+            drive: Default::default(),
             animation: Default::default(),
+            requests: Default::default(),
+            group: Default::default(),
+            variant_rng: None,
+            event_rng: None,
+            variation_rng: None,
             time: Default::default(),
         }
     }
@@ -242,7 +626,16 @@ impl<T: Default> AnimationPlayer2D<T> {
     pub fn new() -> Self {
         Self {
             paused: Default::default(),
+            pending_policy: Default::default(),
+            finish_behaviour: None,
+            seek_events: Default::default(),
+            drive: Default::default(),
             animation: Default::default(),
+            requests: Default::default(),
+            group: Default::default(),
+            variant_rng: None,
+            event_rng: None,
+            variation_rng: None,
             time: Default::default(),
         }
     }
@@ -254,6 +647,7 @@ impl<T: Default> AnimationPlayer2D<T> {
         seek_time: f32,
     ) -> &mut Self {
         self.animation = PlayingAnimation2D {
+            animation_clip_path: handle.path().cloned(),
             animation_clip: handle,
             elapsed,
             seek_time,
@@ -263,11 +657,19 @@ impl<T: Default> AnimationPlayer2D<T> {
     }
 
     /// Start playing an animation, resetting state of the player.
+    ///
+    /// Note: This bypasses the [`AnimationLock`] of the playing animation, even while it holds.
+    /// Use [`Self::request`] to respect the priority and lock of the playing animation.
     pub fn start(&mut self, handle: Handle<AnimationClip2D>) -> &mut Self {
-        self.start_from_time(handle, 0.0, 0.0)
+        self.start_from_time(handle, 0.0, 0.0);
+        self.animation.pending_variation = true;
+        self
     }
 
     /// Start playing an animation, resetting state of the player, unless the requested animation is already playing.
+    ///
+    /// Note: This bypasses the [`AnimationLock`] of the playing animation, even while it holds.
+    /// Use [`Self::request`] to respect the priority and lock of the playing animation.
     pub fn play(&mut self, handle: Handle<AnimationClip2D>) -> &mut Self {
         if self.animation.animation_clip != handle || self.paused() {
             self.start(handle);
@@ -277,7 +679,7 @@ impl<T: Default> AnimationPlayer2D<T> {
 
     /// Start playing an animation from the current `seek_time` and `elapsed` state.
     ///
-    /// This should only be called on animations that have the same duration.
+    /// This should only be called on animations that have the same duration, see [`Self::play_synced`] otherwise.
     pub fn play_continue(&mut self, handle: Handle<AnimationClip2D>) -> &mut Self {
         if self.animation.animation_clip != handle || self.paused() {
             self.start_from_time(handle, self.animation.elapsed, self.animation.seek_time);
@@ -349,7 +751,24 @@ impl<T: Default> AnimationPlayer2D<T> {
         self.animation.repeat
     }
 
+    /// Set if the animation plays forward and backward in turns.
+    ///
+    /// Each pass in one direction counts as a cycle for the repetition behaviour,
+    /// e.g. [`RepeatAnimation::Count(2)`](RepeatAnimation::Count) plays forward once and backward once.
+    /// The sign of the speed changes with the direction.
+    pub fn set_ping_pong(&mut self, ping_pong: bool) -> &mut Self {
+        self.animation.ping_pong = ping_pong;
+        self
+    }
+
+    /// Check if the animation plays forward and backward in turns.
+    pub fn ping_pong(&self) -> bool {
+        self.animation.ping_pong
+    }
+
     /// Check if the animation is playing in reverse.
+    ///
+    /// Note: For ping-pong animations this is the direction of the current pass.
     pub fn reversed(&self) -> bool {
         self.animation.speed < 0.0
     }
@@ -369,6 +788,31 @@ impl<T: Default> AnimationPlayer2D<T> {
         self.paused
     }
 
+    /// Check if the animation clip is still loading.
+    ///
+    /// See also [`Self::set_pending_policy`].
+    pub fn pending(&self) -> bool {
+        self.animation.state == ClipState::Pending
+    }
+
+    /// Check if the animation clip failed to load or its label does not exist.
+    ///
+    /// The [`AnimationClip2DFallback`] is played instead.
+    pub fn failed(&self) -> bool {
+        self.animation.state == ClipState::Failed
+    }
+
+    /// Set how time is handled while the animation clip is still loading.
+    pub fn set_pending_policy(&mut self, pending_policy: PendingPolicy) -> &mut Self {
+        self.pending_policy = pending_policy;
+        self
+    }
+
+    /// Policy for the time while the animation clip is still loading.
+    pub fn pending_policy(&self) -> PendingPolicy {
+        self.pending_policy
+    }
+
     /// Speed of the animation playback
     pub fn speed(&self) -> f32 {
         self.animation.speed
@@ -403,11 +847,16 @@ impl<T: Default> AnimationPlayer2D<T> {
     }
 
     /// Seek time inside of the animation. Always within the range [0.0, clip_duration].
+    ///
+    /// Note: With [`PendingPolicy::Accumulate`] this may exceed the range while the animation clip is still loading.
     pub fn seek_time(&self) -> f32 {
         self.animation.seek_time
     }
 
     /// Seek to a specific time in the animation.
+    ///
+    /// The seek time is neither wrapped nor clamped and the frame is updated with the next animation update.
+    /// See [`Self::seek_to_frame`] and [`Self::seek_to_normalized`] for seeking that takes effect right away.
     pub fn seek_to(&mut self, seek_time: f32) -> &mut Self {
         self.animation.seek_time = seek_time;
         self
@@ -0,0 +1,196 @@
+//! This module allows to play animation clips by their name in an [`AnimationClip2DSet`].
+//!
+
+use bevy::{
+    animation::RepeatAnimation,
+    prelude::{
+        Assets, Component, Deref, DerefMut, Entity, Event, EventWriter, Handle, Query, Reflect,
+        ReflectComponent, Res,
+    },
+};
+
+use crate::{
+    asset::{AnimationClip2D, AnimationClip2DSet, AnimationClips},
+    rng::SplitMix64,
+};
+
+use super::{AnimationPlayer2D, ClipState, PlayingAnimation2D};
+
+/// The [`AnimationClip2DSet`] that the names passed to [`AnimationPlayer2D::play_named`] are resolved against.
+///
+/// This also keeps all animation clips of the set loaded.
+#[derive(Component, Debug, Default, Clone, PartialEq, Eq, Deref, DerefMut, Reflect)]
+#[reflect(Component)]
+pub struct AnimationClip2DSetHandle(pub Handle<AnimationClip2DSet>);
+
+impl From<Handle<AnimationClip2DSet>> for AnimationClip2DSetHandle {
+    fn from(handle: Handle<AnimationClip2DSet>) -> Self {
+        Self(handle)
+    }
+}
+
+/// Sent when the name passed to [`AnimationPlayer2D::play_named`] does not exist in the [`AnimationClip2DSet`] of the entity,
+/// or the entity has no [`AnimationClip2DSetHandle`] to resolve it against.
+#[derive(Debug, Clone, Event)]
+pub struct AnimationClip2DNotFound {
+    /// Entity of the [`AnimationPlayer2D`].
+    pub entity: Entity,
+    /// The requested name of the animation clip.
+    pub name: String,
+}
+
+impl<T: Default> AnimationPlayer2D<T> {
+    /// Start playing the animation clip with the given name, resetting state of the player.
+    ///
+    /// The name is resolved against the [`AnimationClip2DSetHandle`] of the entity once the set is loaded.
+    /// Until then the player is pending, see [`Self::pending`].
+    /// If the name does not exist or the entity has no [`AnimationClip2DSetHandle`], the player fails instead.
+    pub fn start_named(&mut self, name: impl Into<String>) -> &mut Self {
+        self.animation = PlayingAnimation2D {
+            animation_name: Some(name.into()),
+            pending_variation: true,
+            ..Default::default()
+        };
+        self
+    }
+
+    /// Start playing the animation clip with the given name, resetting state of the player, unless the requested animation is already playing.
+    ///
+    /// See also [`Self::start_named`].
+    pub fn play_named(&mut self, name: impl Into<String>) -> &mut Self {
+        let name = name.into();
+        if self.animation_name() != Some(name.as_str()) || self.paused() {
+            self.start_named(name);
+        }
+        self
+    }
+
+    /// Start playing the given animation clip by its name, resetting state of the player.
+    ///
+    /// See also [`Self::start_named`].
+    pub fn start_clip(&mut self, clip: impl AnimationClips) -> &mut Self {
+        self.start_named(clip.name())
+    }
+
+    /// Start playing the given animation clip by its name, resetting state of the player, unless the requested animation is already playing.
+    ///
+    /// See also [`Self::play_named`].
+    pub fn play_clip(&mut self, clip: impl AnimationClips) -> &mut Self {
+        self.play_named(clip.name())
+    }
+
+    /// Name of the animation clip being played, if it was started by name.
+    pub fn animation_name(&self) -> Option<&str> {
+        self.animation.animation_name.as_deref()
+    }
+
+    /// Set the seed for picking the animations of variant groups.
+    ///
+    /// Otherwise the animations are picked with the entity as seed.
+    pub fn set_variant_seed(&mut self, seed: u64) -> &mut Self {
+        self.variant_rng = Some(SplitMix64::new(seed));
+        self
+    }
+
+    /// Pick an animation of the named variant group by weight.
+    fn pick_variant<'a>(
+        &mut self,
+        entity: Entity,
+        animation_clip_set: &'a AnimationClip2DSet,
+        name: &str,
+    ) -> Option<&'a Handle<AnimationClip2D>> {
+        let rng = self
+            .variant_rng
+            .get_or_insert_with(|| SplitMix64::new(entity.to_bits()));
+        animation_clip_set.pick_variant(name, rng)
+    }
+}
+
+/// System that resolves the names of requested animation clips against the [`AnimationClip2DSet`] of the entity.
+pub(crate) fn resolve_animation_names<T: Default + Send + Sync + 'static>(
+    animation_clip_sets: Res<Assets<AnimationClip2DSet>>,
+    mut query: Query<(
+        Entity,
+        Option<&AnimationClip2DSetHandle>,
+        &mut AnimationPlayer2D<T>,
+    )>,
+    mut not_found_events: EventWriter<AnimationClip2DNotFound>,
+) {
+    for (entity, animation_clip_set, mut player) in &mut query {
+        let animation = &player.animation;
+        if animation.state != ClipState::Pending || animation.animation_clip != Handle::default() {
+            continue;
+        }
+        let Some(name) = animation.animation_name.as_ref() else {
+            continue;
+        };
+        let name = name.clone();
+        let Some(animation_clip_set) = animation_clip_set else {
+            // There is no set to resolve the name against
+            player.animation.state = ClipState::Failed;
+
+            not_found_events.write(AnimationClip2DNotFound { entity, name });
+            continue;
+        };
+        let Some(animation_clip_set) = animation_clip_sets.get(&animation_clip_set.0) else {
+            continue;
+        };
+
+        let handle = if animation_clip_set.variants.contains_key(&name) {
+            player.pick_variant(entity, animation_clip_set, &name)
+        } else {
+            animation_clip_set.animations.get(&name)
+        };
+        match handle {
+            Some(handle) => {
+                let animation = &mut player.animation;
+                animation.animation_clip_path = handle.path().cloned();
+                animation.animation_clip = handle.clone();
+            }
+            None => {
+                // Play the fallback animation clip instead
+                player.animation.state = ClipState::Failed;
+
+                not_found_events.write(AnimationClip2DNotFound { entity, name });
+            }
+        }
+    }
+}
+
+/// System that picks the next animation of the variant group being played, before the playing animation repeats.
+pub(crate) fn loop_animation_variants<T: Default + Send + Sync + 'static>(
+    animation_clip_sets: Res<Assets<AnimationClip2DSet>>,
+    mut query: Query<(Entity, &AnimationClip2DSetHandle, &mut AnimationPlayer2D<T>)>,
+) {
+    for (entity, animation_clip_set, mut player) in &mut query {
+        let animation = &player.animation;
+        // Ping-pong animations repeat by playing the animation back
+        let repeats = match animation.repeat {
+            RepeatAnimation::Never => false,
+            RepeatAnimation::Count(n) => animation.completions + 1 < n,
+            RepeatAnimation::Forever => true,
+        };
+        if animation.state != ClipState::Ready
+            || animation.pending_switch.is_some()
+            || animation.pending_variant.is_some()
+            || animation.ping_pong
+            || !repeats
+        {
+            continue;
+        }
+        let Some(name) = animation.animation_name.as_ref() else {
+            continue;
+        };
+        let Some(animation_clip_set) = animation_clip_sets.get(&animation_clip_set.0) else {
+            continue;
+        };
+        if !animation_clip_set.variants.contains_key(name) {
+            continue;
+        }
+
+        let name = name.clone();
+        if let Some(handle) = player.pick_variant(entity, animation_clip_set, &name) {
+            player.animation.pending_variant = Some(handle.clone());
+        }
+    }
+}
@@ -0,0 +1,145 @@
+//! This module handles animation players, whose animation clip is not loaded yet or failed to load.
+//!
+
+use std::sync::Arc;
+
+use bevy::{
+    asset::{AssetLoadError, AssetServer, LoadState},
+    prelude::{AssetId, Assets, Entity, Event, EventWriter, Handle, Query, Res, Resource},
+    reflect::Reflect,
+    time::Time,
+};
+
+use crate::asset::{AnimationClip2D, AnimationClip2DSet};
+
+use super::{AnimationPlayer2D, ClipState, PlayingAnimation2D};
+
+/// Policy of an [`AnimationPlayer2D`] for the time while its animation clip is still loading.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
+pub enum PendingPolicy {
+    /// Playback starts from the beginning once the animation clip is loaded.
+    #[default]
+    StartWhenReady,
+    /// Time accumulates while the animation clip is loading,
+    /// so playback continues as if the animation clip had been loaded from the start.
+    Accumulate,
+}
+
+/// Sent when the animation clip of an [`AnimationPlayer2D`] is loaded and becomes playable.
+#[derive(Debug, Clone, Event)]
+pub struct AnimationClip2DReady {
+    /// Entity of the [`AnimationPlayer2D`].
+    pub entity: Entity,
+    /// The animation clip that became playable.
+    pub animation_clip: AssetId<AnimationClip2D>,
+}
+
+/// Animation clip that is played by an [`AnimationPlayer2D`] instead of its own animation clip,
+/// if that failed to load or its label does not exist. E.g. a visible error frame.
+#[derive(Debug, Default, Clone, Resource)]
+pub struct AnimationClip2DFallback(pub Option<Handle<AnimationClip2D>>);
+
+/// Sent when the animation clip of an [`AnimationPlayer2D`] failed to load or its label does not exist.
+#[derive(Debug, Clone, Event)]
+pub struct AnimationClip2DLoadFailed {
+    /// Entity of the [`AnimationPlayer2D`].
+    pub entity: Entity,
+    /// The animation clip that failed to load.
+    pub animation_clip: AssetId<AnimationClip2D>,
+    /// The reason why the animation clip could not be loaded.
+    pub error: Arc<AssetLoadError>,
+}
+
+/// Determine why the animation clip of the given animation could not be loaded, if loading it has failed.
+///
+/// Animation clips are labeled assets of an [`AnimationClip2DSet`], so the load state of the set has to be considered.
+/// A missing label does not fail the labeled asset, so the set is loaded and held by the animation until it is decided.
+fn load_error(
+    asset_server: &AssetServer,
+    animation_clip_sets: &Assets<AnimationClip2DSet>,
+    animation: &mut PlayingAnimation2D,
+) -> Option<Arc<AssetLoadError>> {
+    if let Some(LoadState::Failed(error)) = asset_server.get_load_state(&animation.animation_clip) {
+        return Some(error);
+    }
+
+    let path = asset_server.get_path(&animation.animation_clip)?;
+    let label = path.label()?;
+    let base_path = path.without_label().into_owned();
+    let animation_clip_set = animation
+        .animation_clip_set
+        .get_or_insert_with(|| asset_server.load(base_path.clone()));
+    match asset_server.get_load_state(&*animation_clip_set)? {
+        LoadState::Failed(error) => Some(error),
+        LoadState::Loaded => {
+            let animation_clip_set = animation_clip_sets.get(&*animation_clip_set)?;
+            if animation_clip_set.animations.contains_key(label) {
+                // The animation clip is added to the assets along with the set
+                return None;
+            }
+
+            let mut all_labels: Vec<String> =
+                animation_clip_set.animations.keys().cloned().collect();
+            all_labels.sort_unstable();
+
+            Some(Arc::new(AssetLoadError::MissingLabel {
+                base_path,
+                label: label.to_string(),
+                all_labels,
+            }))
+        }
+        _ => None,
+    }
+}
+
+/// System that checks if the animation clips of pending animation players are loaded or failed to load.
+pub(crate) fn update_pending_animations<T: Default + Send + Sync + 'static>(
+    time: Res<Time<T>>,
+    asset_server: Res<AssetServer>,
+    animation_clips: Res<Assets<AnimationClip2D>>,
+    animation_clip_sets: Res<Assets<AnimationClip2DSet>>,
+    mut query: Query<(Entity, &mut AnimationPlayer2D<T>)>,
+    mut ready_events: EventWriter<AnimationClip2DReady>,
+    mut failed_events: EventWriter<AnimationClip2DLoadFailed>,
+) {
+    for (entity, mut player) in &mut query {
+        // Failed animation clips are checked as well, because they may be fixed by a hot reload
+        if player.animation.state == ClipState::Ready {
+            continue;
+        }
+
+        let animation_clip_id = player.animation.animation_clip.id();
+        if let Some(animation_clip) = animation_clips.get(animation_clip_id) {
+            let animation = &mut player.animation;
+            animation.duration = Some(animation_clip.duration());
+            animation.state = ClipState::Ready;
+            animation.animation_clip_set = None;
+            if std::mem::take(&mut animation.restored) {
+                continue;
+            }
+
+            ready_events.write(AnimationClip2DReady {
+                entity,
+                animation_clip: animation_clip_id,
+            });
+        } else if player.animation.state == ClipState::Failed {
+            continue;
+        } else if let Some(error) =
+            load_error(&asset_server, &animation_clip_sets, &mut player.animation)
+        {
+            player.animation.state = ClipState::Failed;
+
+            failed_events.write(AnimationClip2DLoadFailed {
+                entity,
+                animation_clip: animation_clip_id,
+                error,
+            });
+        } else if player.pending_policy == PendingPolicy::Accumulate && !player.halted() {
+            // The seek_time is wrapped into the clip duration with the first update once the clip is ready
+            let delta = time.delta_secs() * player.group.speed;
+            let animation = &mut player.animation;
+            animation.elapsed += delta;
+            animation.seek_time += delta * animation.speed;
+        }
+    }
+}
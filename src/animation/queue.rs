@@ -0,0 +1,179 @@
+//! This module allows to queue animation clips, that are played one after another.
+//!
+
+use std::hash::{Hash, Hasher};
+
+use bevy::{
+    animation::RepeatAnimation,
+    prelude::{AssetId, DetectChangesMut, Entity, Event, EventWriter, Handle, Query, Reflect},
+};
+
+use crate::asset::AnimationClip2D;
+
+use super::{AnimationPlayer2D, PlayingAnimation2D, snapshot::hash_repeat_animation};
+
+/// Animation clip waiting in the queue of an [`AnimationPlayer2D`].
+///
+/// The speed is compared bitwise.
+#[derive(Debug, Clone, Reflect)]
+pub struct QueuedAnimation {
+    animation_clip: Handle<AnimationClip2D>,
+    repeat: RepeatAnimation,
+    speed: f32,
+}
+
+impl QueuedAnimation {
+    /// Creates a new QueuedAnimation, that is played once at normal speed.
+    pub fn new(animation_clip: Handle<AnimationClip2D>) -> Self {
+        Self {
+            animation_clip,
+            repeat: RepeatAnimation::Never,
+            speed: 1.0,
+        }
+    }
+
+    /// Set the repetition behaviour of the animation.
+    ///
+    /// Note: An animation with [`RepeatAnimation::Forever`] never finishes, so the queue does not advance past it.
+    pub fn with_repeat_mode(mut self, repeat: RepeatAnimation) -> Self {
+        self.repeat = repeat;
+        self
+    }
+
+    /// Set the speed of the animation playback.
+    pub fn with_speed(mut self, speed: f32) -> Self {
+        self.speed = speed;
+        self
+    }
+
+    /// Handle to the animation clip.
+    pub fn animation_clip(&self) -> &Handle<AnimationClip2D> {
+        &self.animation_clip
+    }
+
+    /// Repetition behavior of the animation.
+    pub fn repeat_mode(&self) -> RepeatAnimation {
+        self.repeat
+    }
+
+    /// Speed of the animation playback.
+    pub fn speed(&self) -> f32 {
+        self.speed
+    }
+}
+
+impl PartialEq for QueuedAnimation {
+    fn eq(&self, other: &Self) -> bool {
+        self.animation_clip == other.animation_clip
+            && self.repeat == other.repeat
+            && self.speed.to_bits() == other.speed.to_bits()
+    }
+}
+
+impl Eq for QueuedAnimation {}
+
+impl Hash for QueuedAnimation {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.animation_clip.hash(state);
+        hash_repeat_animation(self.repeat, state);
+        self.speed.to_bits().hash(state);
+    }
+}
+
+impl From<Handle<AnimationClip2D>> for QueuedAnimation {
+    fn from(animation_clip: Handle<AnimationClip2D>) -> Self {
+        Self::new(animation_clip)
+    }
+}
+
+/// Sent when an animation clip of the queue of an [`AnimationPlayer2D`] starts playing.
+#[derive(Debug, Clone, Event)]
+pub struct QueuedAnimationStarted {
+    /// Entity of the [`AnimationPlayer2D`].
+    pub entity: Entity,
+    /// The animation clip that started playing.
+    pub animation_clip: AssetId<AnimationClip2D>,
+    /// Number of animation clips that were still waiting in the queue, when the animation clip started.
+    pub remaining: usize,
+}
+
+impl PlayingAnimation2D {
+    /// Start playing the next animation clip of the queue.
+    ///
+    /// Returns `false` if the queue is empty.
+    pub(crate) fn start_queued(&mut self) -> bool {
+        let Some(queued) = self.queue.pop_front() else {
+            return false;
+        };
+
+        let queue = std::mem::take(&mut self.queue);
+        let mut started_from_queue = std::mem::take(&mut self.started_from_queue);
+        started_from_queue.push((queued.animation_clip.id(), queue.len()));
+        *self = PlayingAnimation2D {
+            repeat: queued.repeat,
+            speed: queued.speed,
+            animation_clip_path: queued.animation_clip.path().cloned(),
+            animation_clip: queued.animation_clip,
+            queue,
+            started_from_queue,
+            pending_variation: true,
+            ..Default::default()
+        };
+        true
+    }
+}
+
+impl<T: Default> AnimationPlayer2D<T> {
+    /// Add an animation clip to the queue, that is played once the playing animation and all animation clips queued before have finished.
+    ///
+    /// The time left over from the update an animation finished in is carried over to the next animation clip.
+    /// If no animation was started yet, the animation clip is started right away.
+    ///
+    /// Note: Starting another animation clears the queue.
+    pub fn enqueue(&mut self, animation: impl Into<QueuedAnimation>) -> &mut Self {
+        self.animation.queue.push_back(animation.into());
+        if self.animation.animation_clip == Handle::default()
+            && self.animation.animation_name.is_none()
+        {
+            self.animation.start_queued();
+        }
+        self
+    }
+
+    /// Animation clips waiting in the queue, in the order they are played.
+    pub fn queue(&self) -> impl ExactSizeIterator<Item = &QueuedAnimation> {
+        self.animation.queue.iter()
+    }
+
+    /// Remove all animation clips from the queue, the playing animation is not affected.
+    pub fn clear_queue(&mut self) -> &mut Self {
+        self.animation.queue.clear();
+        self
+    }
+}
+
+/// System that sends an event for each animation clip of a queue that started playing.
+pub(crate) fn send_queued_animation_events<T: Default + Send + Sync + 'static>(
+    mut query: Query<(Entity, &mut AnimationPlayer2D<T>)>,
+    mut started_events: EventWriter<QueuedAnimationStarted>,
+) {
+    for (entity, mut player) in &mut query {
+        if player.animation.started_from_queue.is_empty() {
+            continue;
+        }
+
+        let started_from_queue = std::mem::take(
+            &mut player
+                .bypass_change_detection()
+                .animation
+                .started_from_queue,
+        );
+        started_events.write_batch(started_from_queue.into_iter().map(
+            |(animation_clip, remaining)| QueuedAnimationStarted {
+                entity,
+                animation_clip,
+                remaining,
+            },
+        ));
+    }
+}
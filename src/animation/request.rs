@@ -0,0 +1,216 @@
+//! This module allows to request animation clips with a priority, so that important animations are not overridden.
+//!
+
+use std::hash::{Hash, Hasher};
+
+use bevy::{
+    animation::RepeatAnimation,
+    prelude::{Handle, Query, Reflect},
+};
+
+use crate::asset::AnimationClip2D;
+
+use super::{AnimationPlayer2D, snapshot::hash_repeat_animation};
+
+/// Rule until when the playing animation can only be interrupted by requests with a higher priority.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
+pub enum AnimationLock {
+    /// The animation can be interrupted by any request.
+    #[default]
+    None,
+    /// The animation is locked until the frame with the given index into the keyframes is reached.
+    UntilFrame(usize),
+    /// The animation is locked until it finished.
+    UntilFinished,
+}
+
+/// Request to play an animation clip, resolved by priority once per frame.
+///
+/// See [`AnimationPlayer2D::request`].
+#[derive(Debug, Clone, Reflect)]
+pub struct AnimationRequest {
+    animation_clip: Handle<AnimationClip2D>,
+    priority: i32,
+    lock: AnimationLock,
+    repeat: RepeatAnimation,
+    speed: f32,
+}
+
+impl AnimationRequest {
+    /// Creates a new AnimationRequest with priority 0 and no lock, that is played once at normal speed.
+    pub fn new(animation_clip: Handle<AnimationClip2D>) -> Self {
+        Self {
+            animation_clip,
+            priority: 0,
+            lock: AnimationLock::None,
+            repeat: RepeatAnimation::Never,
+            speed: 1.0,
+        }
+    }
+
+    /// Set the priority of the request.
+    pub fn with_priority(mut self, priority: i32) -> Self {
+        self.priority = priority;
+        self
+    }
+
+    /// Set until when the animation can only be interrupted by requests with a higher priority.
+    pub fn with_lock(mut self, lock: AnimationLock) -> Self {
+        self.lock = lock;
+        self
+    }
+
+    /// Set the repetition behaviour of the animation.
+    pub fn with_repeat_mode(mut self, repeat: RepeatAnimation) -> Self {
+        self.repeat = repeat;
+        self
+    }
+
+    /// Set the speed of the animation playback.
+    pub fn with_speed(mut self, speed: f32) -> Self {
+        self.speed = speed;
+        self
+    }
+
+    /// Handle to the animation clip.
+    pub fn animation_clip(&self) -> &Handle<AnimationClip2D> {
+        &self.animation_clip
+    }
+
+    /// Priority of the request.
+    pub fn priority(&self) -> i32 {
+        self.priority
+    }
+
+    /// Until when the animation can only be interrupted by requests with a higher priority.
+    pub fn lock(&self) -> AnimationLock {
+        self.lock
+    }
+
+    /// Repetition behavior of the animation.
+    pub fn repeat_mode(&self) -> RepeatAnimation {
+        self.repeat
+    }
+
+    /// Speed of the animation playback.
+    pub fn speed(&self) -> f32 {
+        self.speed
+    }
+}
+
+impl PartialEq for AnimationRequest {
+    fn eq(&self, other: &Self) -> bool {
+        self.animation_clip == other.animation_clip
+            && self.priority == other.priority
+            && self.lock == other.lock
+            && self.repeat == other.repeat
+            && self.speed.to_bits() == other.speed.to_bits()
+    }
+}
+
+impl Eq for AnimationRequest {}
+
+impl Hash for AnimationRequest {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.animation_clip.hash(state);
+        self.priority.hash(state);
+        self.lock.hash(state);
+        hash_repeat_animation(self.repeat, state);
+        self.speed.to_bits().hash(state);
+    }
+}
+
+impl From<Handle<AnimationClip2D>> for AnimationRequest {
+    fn from(animation_clip: Handle<AnimationClip2D>) -> Self {
+        Self::new(animation_clip)
+    }
+}
+
+impl<T: Default> AnimationPlayer2D<T> {
+    /// Request to play an animation clip.
+    ///
+    /// All requests of a frame are resolved at once before the animations are played, independent of the order they were made in.
+    /// The request with the highest priority starts playing, unless its animation clip is already playing and has not finished.
+    /// While the lock of the playing animation holds, only requests with a higher priority than the playing animation interrupt it.
+    ///
+    /// Note: Animations started directly, e.g. with [`Self::play`], bypass the lock of the playing animation
+    /// and have priority 0 and no lock.
+    pub fn request(&mut self, request: impl Into<AnimationRequest>) -> &mut Self {
+        self.requests.push(request.into());
+        self
+    }
+
+    /// Start playing an animation like [`Self::play`] and release the priority and lock of the playing animation.
+    ///
+    /// This is the escape hatch to leave a locked animation, e.g. when the entity dies during an attack.
+    /// If the requested animation is already playing, it continues and can be interrupted by any request.
+    pub fn play_unlocked(&mut self, handle: Handle<AnimationClip2D>) -> &mut Self {
+        self.play(handle);
+        self.animation.priority = 0;
+        self.animation.lock = AnimationLock::None;
+        self
+    }
+
+    /// Requests made since the last time requests were resolved.
+    pub fn requests(&self) -> &[AnimationRequest] {
+        &self.requests
+    }
+
+    /// Priority of the playing animation.
+    pub fn priority(&self) -> i32 {
+        self.animation.priority
+    }
+
+    /// Until when the playing animation can only be interrupted by requests with a higher priority.
+    pub fn lock(&self) -> AnimationLock {
+        self.animation.lock
+    }
+
+    /// Check if the lock of the playing animation still holds.
+    pub fn locked(&self) -> bool {
+        match self.animation.lock {
+            AnimationLock::None => false,
+            AnimationLock::UntilFrame(frame) => {
+                !self.finished() && self.animation.frame.is_none_or(|current| current < frame)
+            }
+            AnimationLock::UntilFinished => !self.finished(),
+        }
+    }
+}
+
+/// System that starts the animation clip of the request with the highest priority, if the playing animation can be interrupted.
+pub(crate) fn resolve_animation_requests<T: Default + Send + Sync + 'static>(
+    mut query: Query<&mut AnimationPlayer2D<T>>,
+) {
+    for mut player in &mut query {
+        if player.requests.is_empty() {
+            continue;
+        }
+
+        // Ties are broken by the animation clip, to not depend on the order of the requests
+        let requests = std::mem::take(&mut player.requests);
+        let Some(request) = requests.into_iter().max_by(|a, b| {
+            a.priority
+                .cmp(&b.priority)
+                .then_with(|| a.animation_clip.id().cmp(&b.animation_clip.id()))
+        }) else {
+            continue;
+        };
+
+        if player.locked() && request.priority <= player.animation.priority {
+            continue;
+        }
+
+        // A finished animation is not playing anymore, so it is played again
+        if player.finished() {
+            player.start(request.animation_clip);
+        } else {
+            player.play(request.animation_clip);
+        }
+        player
+            .set_repeat_mode(request.repeat)
+            .set_speed(request.speed);
+        player.animation.priority = request.priority;
+        player.animation.lock = request.lock;
+    }
+}
@@ -0,0 +1,124 @@
+//! This module allows entities to return to a resting animation clip, once other animations finished.
+//!
+
+use bevy::{
+    animation::RepeatAnimation,
+    prelude::{Component, Handle, Query, Reflect, ReflectComponent},
+};
+
+use crate::asset::AnimationClip2D;
+
+use super::{AnimationPlayer2D, PlayingAnimation2D};
+
+/// Animation clip the [`AnimationPlayer2D`] of the entity returns to, once an animation finished.
+///
+/// If no animation was started yet, the resting animation clip is started right away.
+#[derive(Component, Debug, Clone, PartialEq, Reflect)]
+#[reflect(Component)]
+pub struct RestingAnimation2D {
+    animation_clip: Handle<AnimationClip2D>,
+    repeat: RepeatAnimation,
+    speed: f32,
+    carry_leftover: bool,
+}
+
+impl RestingAnimation2D {
+    /// Creates a new RestingAnimation2D, that repeats forever at normal speed and continues with the time left over.
+    pub fn new(animation_clip: Handle<AnimationClip2D>) -> Self {
+        Self {
+            animation_clip,
+            repeat: RepeatAnimation::Forever,
+            speed: 1.0,
+            carry_leftover: true,
+        }
+    }
+
+    /// Set the repetition behaviour of the resting animation.
+    pub fn with_repeat_mode(mut self, repeat: RepeatAnimation) -> Self {
+        self.repeat = repeat;
+        self
+    }
+
+    /// Set the speed of the resting animation playback.
+    pub fn with_speed(mut self, speed: f32) -> Self {
+        self.speed = speed;
+        self
+    }
+
+    /// Set if the time left over from the update the previous animation finished in is carried over.
+    ///
+    /// Otherwise the resting animation starts at its first frame.
+    pub fn with_carry_leftover(mut self, carry_leftover: bool) -> Self {
+        self.carry_leftover = carry_leftover;
+        self
+    }
+
+    /// Handle to the resting animation clip.
+    pub fn animation_clip(&self) -> &Handle<AnimationClip2D> {
+        &self.animation_clip
+    }
+
+    /// Repetition behavior of the resting animation.
+    pub fn repeat_mode(&self) -> RepeatAnimation {
+        self.repeat
+    }
+
+    /// Speed of the resting animation playback.
+    pub fn speed(&self) -> f32 {
+        self.speed
+    }
+
+    /// Check if the time left over from the update the previous animation finished in is carried over.
+    pub fn carry_leftover(&self) -> bool {
+        self.carry_leftover
+    }
+}
+
+impl PlayingAnimation2D {
+    /// Start playing the resting animation clip.
+    ///
+    /// Returns if the time left over is carried over, or `None` if there is no resting animation clip.
+    pub(crate) fn start_resting(&mut self) -> Option<bool> {
+        let resting = self.resting.take()?;
+
+        *self = PlayingAnimation2D {
+            repeat: resting.repeat,
+            speed: resting.speed,
+            animation_clip_path: resting.animation_clip.path().cloned(),
+            animation_clip: resting.animation_clip,
+            started_from_queue: std::mem::take(&mut self.started_from_queue),
+            pending_variation: true,
+            ..Default::default()
+        };
+        Some(resting.carry_leftover)
+    }
+}
+
+/// System that lets animations return to the [`RestingAnimation2D`] of their entity once they finished.
+pub(crate) fn update_resting_animations<T: Default + Send + Sync + 'static>(
+    mut query: Query<(Option<&RestingAnimation2D>, &mut AnimationPlayer2D<T>)>,
+) {
+    for (resting, mut player) in &mut query {
+        let animation = &player.animation;
+        let Some(resting) = resting else {
+            // The component was removed
+            if animation.resting.is_some() {
+                player.animation.resting = None;
+            }
+            continue;
+        };
+
+        if animation.animation_clip == Handle::default() && animation.animation_name.is_none() {
+            player
+                .start(resting.animation_clip.clone())
+                .set_repeat_mode(resting.repeat)
+                .set_speed(resting.speed);
+        } else if animation.animation_clip == resting.animation_clip {
+            if animation.resting.is_some() {
+                player.animation.resting = None;
+            }
+        } else if animation.resting.as_ref() != Some(resting) {
+            player.animation.resting = Some(resting.clone());
+        }
+    }
+}
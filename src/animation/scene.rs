@@ -0,0 +1,59 @@
+//! This module keeps the [`AssetPath`](bevy::asset::AssetPath) of the animation clip of each [`AnimationPlayer2D`] up to date,
+//! so players can be saved to and loaded from a `DynamicScene` or any other reflection based format.
+//!
+//! The [`Handle<AnimationClip2D>`] itself is not serializable, so it is restored from the path once the player was loaded.
+//!
+
+use bevy::{
+    asset::AssetServer,
+    prelude::{Changed, DetectChangesMut, Handle, Query, Res},
+};
+
+use crate::asset::AnimationClip2D;
+
+use super::{AnimationPlayer2D, ClipState};
+
+/// System that records the asset path (including the label) of the playing animation clip,
+/// if the handle did not provide one.
+pub(crate) fn record_animation_clip_paths<T: Default + Send + Sync + 'static>(
+    asset_server: Res<AssetServer>,
+    mut query: Query<&mut AnimationPlayer2D<T>, Changed<AnimationPlayer2D<T>>>,
+) {
+    for mut player in &mut query {
+        let animation = &player.animation;
+        if animation.animation_clip_path.is_some() || animation.animation_clip == Handle::default()
+        {
+            continue;
+        }
+
+        if let Some(path) = asset_server.get_path(animation.animation_clip.id()) {
+            // Recording the path is not a change of the playback state
+            player
+                .bypass_change_detection()
+                .animation
+                .animation_clip_path = Some(path.into_owned());
+        }
+    }
+}
+
+/// System that loads the animation clip of players, that only know the asset path of their clip.
+/// This is the case for players spawned from a scene.
+pub(crate) fn load_animation_clips_from_paths<T: Default + Send + Sync + 'static>(
+    asset_server: Res<AssetServer>,
+    mut query: Query<&mut AnimationPlayer2D<T>, Changed<AnimationPlayer2D<T>>>,
+) {
+    for mut player in &mut query {
+        let animation = &player.animation;
+        if animation.animation_clip != Handle::default() {
+            continue;
+        }
+
+        if let Some(path) = animation.animation_clip_path.clone() {
+            let handle: Handle<AnimationClip2D> = asset_server.load(path);
+            let animation = &mut player.animation;
+            animation.animation_clip = handle;
+            animation.duration = None;
+            animation.state = ClipState::Pending;
+        }
+    }
+}
@@ -0,0 +1,401 @@
+//! This module allows to capture and restore the complete playback state of an [`AnimationPlayer2D`], e.g. for rollback networking.
+//!
+
+use std::hash::{Hash, Hasher};
+
+use bevy::{animation::RepeatAnimation, prelude::Handle, reflect::Reflect};
+
+use crate::{asset::AnimationClip2D, rng::SplitMix64};
+
+use super::{
+    AnimationDrive, AnimationLock, AnimationPlayer2D, ClipState, FinishBehaviour, PendingPolicy,
+    PendingSwitch, PlayingAnimation2D, QueuedAnimation, RestingAnimation2D, SeekEvents,
+    sync::PendingSync,
+};
+
+/// Complete playback state of an [`AnimationPlayer2D`].
+///
+/// Snapshots can be compared and hashed, so they can be used to detect desyncs.
+/// Floating point values are compared bitwise.
+#[derive(Debug, Clone, Reflect)]
+pub struct AnimationPlayer2DSnapshot {
+    animation_clip: Handle<AnimationClip2D>,
+    animation_name: Option<String>,
+    repeat: RepeatAnimation,
+    ping_pong: bool,
+    speed: f32,
+    elapsed: f32,
+    seek_time: f32,
+    completions: u32,
+    completions_this_update: u32,
+    stepped_completions: u32,
+    last_frame: Option<usize>,
+    frame: Option<usize>,
+    crossed_frames: Vec<(usize, bool)>,
+    priority: i32,
+    lock: AnimationLock,
+    pending_switch: Option<PendingSwitch>,
+    pending_variant: Option<Handle<AnimationClip2D>>,
+    queue: Vec<QueuedAnimation>,
+    resting: Option<RestingAnimation2D>,
+    pending_sync: Option<PendingSync>,
+    pending_variation: bool,
+    ready: bool,
+    finish_applied: bool,
+    paused: bool,
+    pending_policy: PendingPolicy,
+    finish_behaviour: Option<FinishBehaviour>,
+    seek_events: SeekEvents,
+    drive: AnimationDrive,
+    variant_rng: Option<SplitMix64>,
+    event_rng: Option<SplitMix64>,
+    variation_rng: Option<SplitMix64>,
+}
+
+/// Hash a [`RepeatAnimation`], which does not implement [`Hash`].
+pub(crate) fn hash_repeat_animation<H: Hasher>(repeat: RepeatAnimation, state: &mut H) {
+    match repeat {
+        RepeatAnimation::Never => 0u8.hash(state),
+        RepeatAnimation::Count(n) => {
+            1u8.hash(state);
+            n.hash(state);
+        }
+        RepeatAnimation::Forever => 2u8.hash(state),
+    }
+}
+
+/// Hash an [`AnimationDrive`], which does not implement [`Hash`].
+fn hash_animation_drive<H: Hasher>(drive: AnimationDrive, state: &mut H) {
+    match drive {
+        AnimationDrive::Time => 0u8.hash(state),
+        AnimationDrive::Normalized(t) => {
+            1u8.hash(state);
+            t.to_bits().hash(state);
+        }
+        AnimationDrive::Seconds(seconds) => {
+            2u8.hash(state);
+            seconds.to_bits().hash(state);
+        }
+        AnimationDrive::Tempo(offset) => {
+            3u8.hash(state);
+            offset.to_bits().hash(state);
+        }
+    }
+}
+
+/// Compare two [`AnimationDrive`]s, comparing their values bitwise.
+fn animation_drive_eq(a: AnimationDrive, b: AnimationDrive) -> bool {
+    match (a, b) {
+        (AnimationDrive::Time, AnimationDrive::Time) => true,
+        (AnimationDrive::Normalized(a), AnimationDrive::Normalized(b))
+        | (AnimationDrive::Seconds(a), AnimationDrive::Seconds(b))
+        | (AnimationDrive::Tempo(a), AnimationDrive::Tempo(b)) => a.to_bits() == b.to_bits(),
+        _ => false,
+    }
+}
+
+/// Compare two [`RestingAnimation2D`]s, comparing their speed bitwise.
+fn resting_animation_eq(a: &RestingAnimation2D, b: &RestingAnimation2D) -> bool {
+    a.animation_clip() == b.animation_clip()
+        && a.repeat_mode() == b.repeat_mode()
+        && a.speed().to_bits() == b.speed().to_bits()
+        && a.carry_leftover() == b.carry_leftover()
+}
+
+/// Compare two [`PendingSync`]s, comparing their seek time bitwise.
+fn pending_sync_eq(a: &PendingSync, b: &PendingSync) -> bool {
+    a.from == b.from && a.seek_time.to_bits() == b.seek_time.to_bits() && a.mode == b.mode
+}
+
+impl AnimationPlayer2DSnapshot {
+    /// Handle to the animation clip that was played.
+    pub fn animation_clip(&self) -> &Handle<AnimationClip2D> {
+        &self.animation_clip
+    }
+
+    /// Name of the animation clip that was played, if it was started by name.
+    pub fn animation_name(&self) -> Option<&str> {
+        self.animation_name.as_deref()
+    }
+
+    /// Repetition behavior of the animation.
+    pub fn repeat_mode(&self) -> RepeatAnimation {
+        self.repeat
+    }
+
+    /// Did the animation play forward and backward in turns.
+    pub fn ping_pong(&self) -> bool {
+        self.ping_pong
+    }
+
+    /// Speed of the animation playback.
+    pub fn speed(&self) -> f32 {
+        self.speed
+    }
+
+    /// Time elapsed playing the animation.
+    pub fn elapsed(&self) -> f32 {
+        self.elapsed
+    }
+
+    /// Seek time inside of the animation.
+    pub fn seek_time(&self) -> f32 {
+        self.seek_time
+    }
+
+    /// Number of times the animation has completed.
+    pub fn completions(&self) -> u32 {
+        self.completions
+    }
+
+    /// Frame of the animation before the last update, if any.
+    pub fn last_frame(&self) -> Option<usize> {
+        self.last_frame
+    }
+
+    /// Current frame of the animation, if any.
+    pub fn frame(&self) -> Option<usize> {
+        self.frame
+    }
+
+    /// Priority of the animation.
+    pub fn priority(&self) -> i32 {
+        self.priority
+    }
+
+    /// Until when the animation could only be interrupted by requests with a higher priority.
+    pub fn lock(&self) -> AnimationLock {
+        self.lock
+    }
+
+    /// Switch that waited for the animation to reach its switch point, if any.
+    pub fn pending_switch(&self) -> Option<&PendingSwitch> {
+        self.pending_switch.as_ref()
+    }
+
+    /// Animation clips that waited in the queue.
+    pub fn queue(&self) -> &[QueuedAnimation] {
+        &self.queue
+    }
+
+    /// Resting animation clip the player returned to once the animation finished, if any.
+    pub fn resting(&self) -> Option<&RestingAnimation2D> {
+        self.resting.as_ref()
+    }
+
+    /// Was the animation paused.
+    pub fn paused(&self) -> bool {
+        self.paused
+    }
+
+    /// Policy for the time while the animation clip was loading.
+    pub fn pending_policy(&self) -> PendingPolicy {
+        self.pending_policy
+    }
+
+    /// What happened once the animation finished, overriding the animation clip.
+    pub fn finish_behaviour(&self) -> Option<FinishBehaviour> {
+        self.finish_behaviour
+    }
+
+    /// Whether the animation events of the frames crossed by seeking were sent.
+    pub fn seek_events(&self) -> SeekEvents {
+        self.seek_events
+    }
+
+    /// What drove the position of the animation.
+    pub fn drive(&self) -> AnimationDrive {
+        self.drive
+    }
+}
+
+impl PartialEq for AnimationPlayer2DSnapshot {
+    fn eq(&self, other: &Self) -> bool {
+        self.animation_clip == other.animation_clip
+            && self.animation_name == other.animation_name
+            && self.repeat == other.repeat
+            && self.ping_pong == other.ping_pong
+            && self.speed.to_bits() == other.speed.to_bits()
+            && self.elapsed.to_bits() == other.elapsed.to_bits()
+            && self.seek_time.to_bits() == other.seek_time.to_bits()
+            && self.completions == other.completions
+            && self.completions_this_update == other.completions_this_update
+            && self.stepped_completions == other.stepped_completions
+            && self.last_frame == other.last_frame
+            && self.frame == other.frame
+            && self.crossed_frames == other.crossed_frames
+            && self.priority == other.priority
+            && self.lock == other.lock
+            && self.pending_switch == other.pending_switch
+            && self.pending_variant == other.pending_variant
+            && self.queue == other.queue
+            && match (&self.resting, &other.resting) {
+                (Some(a), Some(b)) => resting_animation_eq(a, b),
+                (a, b) => a.is_none() && b.is_none(),
+            }
+            && match (&self.pending_sync, &other.pending_sync) {
+                (Some(a), Some(b)) => pending_sync_eq(a, b),
+                (a, b) => a.is_none() && b.is_none(),
+            }
+            && self.pending_variation == other.pending_variation
+            && self.ready == other.ready
+            && self.finish_applied == other.finish_applied
+            && self.paused == other.paused
+            && self.pending_policy == other.pending_policy
+            && self.finish_behaviour == other.finish_behaviour
+            && self.seek_events == other.seek_events
+            && animation_drive_eq(self.drive, other.drive)
+            && self.variant_rng == other.variant_rng
+            && self.event_rng == other.event_rng
+            && self.variation_rng == other.variation_rng
+    }
+}
+
+impl Eq for AnimationPlayer2DSnapshot {}
+
+impl Hash for AnimationPlayer2DSnapshot {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.animation_clip.hash(state);
+        self.animation_name.hash(state);
+        hash_repeat_animation(self.repeat, state);
+        self.ping_pong.hash(state);
+        self.speed.to_bits().hash(state);
+        self.elapsed.to_bits().hash(state);
+        self.seek_time.to_bits().hash(state);
+        self.completions.hash(state);
+        self.completions_this_update.hash(state);
+        self.stepped_completions.hash(state);
+        self.last_frame.hash(state);
+        self.frame.hash(state);
+        self.crossed_frames.hash(state);
+        self.priority.hash(state);
+        self.lock.hash(state);
+        self.pending_switch.hash(state);
+        self.pending_variant.hash(state);
+        self.queue.hash(state);
+        self.resting.is_some().hash(state);
+        if let Some(resting) = &self.resting {
+            resting.animation_clip().hash(state);
+            hash_repeat_animation(resting.repeat_mode(), state);
+            resting.speed().to_bits().hash(state);
+            resting.carry_leftover().hash(state);
+        }
+        self.pending_sync.is_some().hash(state);
+        if let Some(pending_sync) = &self.pending_sync {
+            pending_sync.from.hash(state);
+            pending_sync.seek_time.to_bits().hash(state);
+            pending_sync.mode.hash(state);
+        }
+        self.pending_variation.hash(state);
+        self.ready.hash(state);
+        self.finish_applied.hash(state);
+        self.paused.hash(state);
+        self.pending_policy.hash(state);
+        self.finish_behaviour.hash(state);
+        self.seek_events.hash(state);
+        hash_animation_drive(self.drive, state);
+        self.variant_rng.hash(state);
+        self.event_rng.hash(state);
+        self.variation_rng.hash(state);
+    }
+}
+
+impl<T: Default> AnimationPlayer2D<T> {
+    /// Capture the complete playback state of this player.
+    pub fn snapshot(&self) -> AnimationPlayer2DSnapshot {
+        AnimationPlayer2DSnapshot {
+            animation_clip: self.animation.animation_clip.clone(),
+            animation_name: self.animation.animation_name.clone(),
+            repeat: self.animation.repeat,
+            ping_pong: self.animation.ping_pong,
+            speed: self.animation.speed,
+            elapsed: self.animation.elapsed,
+            seek_time: self.animation.seek_time,
+            completions: self.animation.completions,
+            completions_this_update: self.animation.completions_this_update,
+            stepped_completions: self.animation.stepped_completions,
+            last_frame: self.animation.last_frame,
+            frame: self.animation.frame,
+            crossed_frames: self.animation.crossed_frames.clone(),
+            priority: self.animation.priority,
+            lock: self.animation.lock,
+            pending_switch: self.animation.pending_switch.clone(),
+            pending_variant: self.animation.pending_variant.clone(),
+            queue: self.animation.queue.iter().cloned().collect(),
+            resting: self.animation.resting.clone(),
+            pending_sync: self.animation.pending_sync.clone(),
+            pending_variation: self.animation.pending_variation,
+            ready: self.animation.state == ClipState::Ready || self.animation.restored,
+            finish_applied: self.animation.finish_applied,
+            paused: self.paused,
+            pending_policy: self.pending_policy,
+            finish_behaviour: self.finish_behaviour,
+            seek_events: self.seek_events,
+            drive: self.drive,
+            variant_rng: self.variant_rng.clone(),
+            event_rng: self.event_rng.clone(),
+            variation_rng: self.variation_rng.clone(),
+        }
+    }
+
+    /// Restore the playback state of this player from a snapshot.
+    ///
+    /// Taking a snapshot right after restoring yields the same snapshot.
+    /// The frame of the snapshot is considered to be already reached,
+    /// so animation events of that frame are not sent again.
+    /// Cycles finished and frames crossed on the update of the snapshot are restored as well,
+    /// so e.g. [`Self::just_finished_cycle`] reads the same as on that update.
+    pub fn restore(&mut self, snapshot: &AnimationPlayer2DSnapshot) -> &mut Self {
+        // A different animation clip or name is loaded or resolved again,
+        // but an animation clip that was playable does not send another ready event
+        let (duration, state) = if self.animation.animation_clip == snapshot.animation_clip
+            && self.animation.animation_name == snapshot.animation_name
+            && (self.animation.state == ClipState::Ready) == snapshot.ready
+        {
+            (self.animation.duration, self.animation.state)
+        } else {
+            (None, ClipState::Pending)
+        };
+
+        self.paused = snapshot.paused;
+        self.pending_policy = snapshot.pending_policy;
+        self.finish_behaviour = snapshot.finish_behaviour;
+        self.seek_events = snapshot.seek_events;
+        self.drive = snapshot.drive;
+        self.variant_rng = snapshot.variant_rng.clone();
+        self.event_rng = snapshot.event_rng.clone();
+        self.variation_rng = snapshot.variation_rng.clone();
+        self.animation = PlayingAnimation2D {
+            repeat: snapshot.repeat,
+            ping_pong: snapshot.ping_pong,
+            speed: snapshot.speed,
+            elapsed: snapshot.elapsed,
+            duration,
+            last_frame: snapshot.last_frame,
+            frame: snapshot.frame,
+            crossed_frames: snapshot.crossed_frames.clone(),
+            seek_time: snapshot.seek_time,
+            animation_clip: snapshot.animation_clip.clone(),
+            animation_clip_path: snapshot.animation_clip.path().cloned(),
+            animation_name: snapshot.animation_name.clone(),
+            animation_clip_set: None,
+            completions: snapshot.completions,
+            completions_this_update: snapshot.completions_this_update,
+            stepped_completions: snapshot.stepped_completions,
+            state,
+            priority: snapshot.priority,
+            lock: snapshot.lock,
+            pending_switch: snapshot.pending_switch.clone(),
+            pending_variant: snapshot.pending_variant.clone(),
+            pending_sync: snapshot.pending_sync.clone(),
+            queue: snapshot.queue.iter().cloned().collect(),
+            resting: snapshot.resting.clone(),
+            started_from_queue: Vec::new(),
+            pending_variation: snapshot.pending_variation,
+            suppressed_events: Vec::new(),
+            finish_applied: snapshot.finish_applied,
+            restored: snapshot.ready && state == ClipState::Pending,
+        };
+        self
+    }
+}
@@ -0,0 +1,224 @@
+//! This module drives [`AnimationPlayer2D`]s with an [`AnimationStateMachine2D`].
+//!
+
+use bevy::{
+    animation::RepeatAnimation,
+    log::warn,
+    platform::collections::HashMap,
+    prelude::{
+        Assets, Component, Entity, EventWriter, Handle, Query, Reflect, ReflectComponent, Res,
+    },
+};
+
+use crate::asset::{
+    AnimationClip2DSet, AnimationCondition, AnimationParameter, AnimationStateMachine2D,
+    AnimationTransition2D,
+};
+
+use super::{AnimationClip2DNotFound, AnimationPlayer2D, AnimationRequest};
+
+/// Drives the [`AnimationPlayer2D`] of the entity with an [`AnimationStateMachine2D`].
+///
+/// Parameters set on this component override the default values of the state machine.
+/// The animation clip of the current state is played with an [`AnimationRequest`] with the priority of the state,
+/// so animations locked with a higher or equal priority are not interrupted.
+#[derive(Component, Debug, Default, Clone, Reflect)]
+#[reflect(Component)]
+pub struct AnimationController2D {
+    state_machine: Handle<AnimationStateMachine2D>,
+    state: Option<String>,
+    parameters: HashMap<String, AnimationParameter>,
+}
+
+impl AnimationController2D {
+    /// Creates a new AnimationController2D, that starts in the initial state of the state machine.
+    pub fn new(state_machine: Handle<AnimationStateMachine2D>) -> Self {
+        Self {
+            state_machine,
+            ..Default::default()
+        }
+    }
+
+    /// Handle to the state machine.
+    pub fn state_machine(&self) -> &Handle<AnimationStateMachine2D> {
+        &self.state_machine
+    }
+
+    /// Name of the current state, if the state machine was started already.
+    pub fn state(&self) -> Option<&str> {
+        self.state.as_deref()
+    }
+
+    /// Value of the parameter set on this controller, if any.
+    pub fn parameter(&self, name: &str) -> Option<AnimationParameter> {
+        self.parameters.get(name).copied()
+    }
+
+    /// Set a parameter.
+    pub fn set_parameter(
+        &mut self,
+        name: impl Into<String>,
+        value: AnimationParameter,
+    ) -> &mut Self {
+        self.parameters.insert(name.into(), value);
+        self
+    }
+
+    /// Set a bool parameter.
+    pub fn set_bool(&mut self, name: impl Into<String>, value: bool) -> &mut Self {
+        self.set_parameter(name, AnimationParameter::Bool(value))
+    }
+
+    /// Set a float parameter.
+    pub fn set_float(&mut self, name: impl Into<String>, value: f32) -> &mut Self {
+        self.set_parameter(name, AnimationParameter::Float(value))
+    }
+
+    /// Set an int parameter.
+    pub fn set_int(&mut self, name: impl Into<String>, value: i32) -> &mut Self {
+        self.set_parameter(name, AnimationParameter::Int(value))
+    }
+
+    /// Set a trigger, which stays set until a transition consumes it.
+    pub fn set_trigger(&mut self, name: impl Into<String>) -> &mut Self {
+        self.set_parameter(name, AnimationParameter::Trigger(true))
+    }
+
+    /// Reset a trigger.
+    pub fn reset_trigger(&mut self, name: impl Into<String>) -> &mut Self {
+        self.set_parameter(name, AnimationParameter::Trigger(false))
+    }
+}
+
+/// Check if the transition can happen.
+fn transition_allowed<T: Default>(
+    transition: &AnimationTransition2D,
+    state_machine: &AnimationStateMachine2D,
+    controller: &AnimationController2D,
+    player: &AnimationPlayer2D<T>,
+) -> bool {
+    if let Some(exit_time) = transition.exit_time {
+        let Some(duration) = player.duration() else {
+            return false;
+        };
+        let normalized_time = player.completions() as f32 + player.seek_time() / duration;
+        if normalized_time < exit_time {
+            return false;
+        }
+    }
+
+    transition.conditions.iter().all(|condition| {
+        controller
+            .parameter(condition.parameter())
+            .or_else(|| {
+                state_machine
+                    .parameters()
+                    .get(condition.parameter())
+                    .copied()
+            })
+            .is_some_and(|value| condition.is_met(value))
+    })
+}
+
+/// System that evaluates the transitions of all state machines and requests the animation clip of the current state.
+pub(crate) fn run_animation_state_machines<T: Default + Send + Sync + 'static>(
+    state_machines: Res<Assets<AnimationStateMachine2D>>,
+    animation_clip_sets: Res<Assets<AnimationClip2DSet>>,
+    mut query: Query<(
+        Entity,
+        &mut AnimationController2D,
+        &mut AnimationPlayer2D<T>,
+    )>,
+    mut not_found_events: EventWriter<AnimationClip2DNotFound>,
+) {
+    for (entity, mut controller, mut player) in &mut query {
+        let Some(state_machine) = state_machines.get(&controller.state_machine) else {
+            continue;
+        };
+        let Some(animation_clip_set) = animation_clip_sets.get(state_machine.clips()) else {
+            continue;
+        };
+
+        // A hot reload may have removed the current state, in which case the state machine starts over
+        let current = controller.state.clone().filter(|current| {
+            let exists = state_machine.states().contains_key(current);
+            if !exists {
+                warn!(
+                    "State {} of the AnimationController2D of {} no longer exists, starting over in the initial state",
+                    current, entity
+                );
+            }
+            exists
+        });
+        let transition = current.as_deref().and_then(|current| {
+            // Transitions from any state take precedence, but do not restart the current state.
+            // Transitions to the current state restart it only once its animation clip has finished,
+            // otherwise a condition that stays met would restart it every frame.
+            state_machine
+                .any_state_transitions()
+                .iter()
+                .filter(|transition| transition.to != current)
+                .chain(
+                    state_machine
+                        .transitions(current)
+                        .iter()
+                        .filter(|transition| transition.to != current || player.finished()),
+                )
+                .find(|transition| {
+                    transition_allowed(transition, state_machine, &controller, &player)
+                })
+        });
+        let next_state = match (transition, current.as_deref()) {
+            (Some(transition), _) => transition.to.as_str(),
+            (None, None) => state_machine.initial_state(),
+            // The current state is requested again, if its request was rejected or another animation was started
+            (None, Some(current)) => match state_machine
+                .states()
+                .get(current)
+                .and_then(|state| animation_clip_set.animations.get(&state.clip))
+            {
+                Some(handle) if !player.clip_playing(handle) => current,
+                _ => continue,
+            },
+        };
+        let Some(state) = state_machine.states().get(next_state) else {
+            continue;
+        };
+
+        // The transition waits until the lock of the playing animation is released, like its request would
+        if player.locked() && state.priority <= player.priority() {
+            continue;
+        }
+
+        // Consume the triggers of the transition
+        for condition in transition
+            .iter()
+            .flat_map(|transition| &transition.conditions)
+        {
+            if let AnimationCondition::Triggered(name) = condition {
+                controller.reset_trigger(name);
+            }
+        }
+
+        controller.state = Some(next_state.to_string());
+        match animation_clip_set.animations.get(&state.clip) {
+            Some(handle) => {
+                player.request(
+                    AnimationRequest::new(handle.clone())
+                        .with_priority(state.priority)
+                        .with_repeat_mode(match state.repeat {
+                            Some(count) => RepeatAnimation::Count(count),
+                            None => RepeatAnimation::Forever,
+                        })
+                        .with_speed(state.speed),
+                );
+            }
+            None => {
+                not_found_events.write(AnimationClip2DNotFound {
+                    entity,
+                    name: state.clip.clone(),
+                });
+            }
+        }
+    }
+}
@@ -0,0 +1,200 @@
+//! This module allows to defer switching to another animation clip until the playing animation reaches a switch point.
+//!
+
+use std::hash::{Hash, Hasher};
+
+use bevy::{prelude::Handle, reflect::Reflect};
+
+use crate::asset::AnimationClip2D;
+
+use super::{AnimationPlayer2D, PlayingAnimation2D};
+
+/// Point of the playing animation at which a requested switch to another animation clip happens.
+#[derive(Debug, Clone, Copy, PartialEq, Reflect)]
+pub enum SwitchPoint {
+    /// The end of the current cycle, or its start when playing in reverse.
+    EndOfCycle,
+    /// The next occurrence of the frame with the given index into the keyframes.
+    Frame(usize),
+    /// The next occurrence of the normalized time in the range [0.0, 1.0].
+    NormalizedTime(f32),
+}
+
+impl SwitchPoint {
+    /// Distance in seek time from `seek_time` to the next occurrence of the switch point in playback direction.
+    ///
+    /// Guaranteed to be in the range (0.0, clip_duration] or (0.0, 2 * clip_duration] for ping-pong animations.
+    pub(crate) fn distance(
+        self,
+        seek_time: f32,
+        reversed: bool,
+        ping_pong: bool,
+        animation_clip: &AnimationClip2D,
+    ) -> f32 {
+        let duration = animation_clip.duration();
+        let frames = animation_clip.keyframe_timestamps().len();
+        // Seek times at which the switch point is reached playing forward and in reverse
+        let (forward, reverse) = match self {
+            SwitchPoint::EndOfCycle => (duration, 0.0),
+            SwitchPoint::Frame(frame) => {
+                let frame = frame.min(frames - 1);
+                // Playing in reverse, a frame is reached at its end
+                (
+                    animation_clip.frame_start(frame),
+                    if frame + 1 < frames {
+                        animation_clip.frame_start(frame + 1)
+                    } else {
+                        duration
+                    },
+                )
+            }
+            SwitchPoint::NormalizedTime(t) => {
+                (t.clamp(0.0, 1.0) * duration, t.clamp(0.0, 1.0) * duration)
+            }
+        };
+
+        if ping_pong {
+            // The switch point is reached in this pass or after the next bounce
+            return match reversed {
+                false if forward > seek_time => forward - seek_time,
+                false => (duration - seek_time) + (duration - reverse),
+                true if reverse < seek_time => seek_time - reverse,
+                true => seek_time + forward,
+            };
+        }
+
+        let distance = if reversed {
+            seek_time - reverse
+        } else {
+            forward - seek_time
+        };
+        // A switch point that is reached right now occurs next in the following cycle
+        let distance = distance.rem_euclid(duration);
+        if distance > 0.0 { distance } else { duration }
+    }
+}
+
+impl Eq for SwitchPoint {}
+
+impl Hash for SwitchPoint {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        match self {
+            SwitchPoint::EndOfCycle => 0u8.hash(state),
+            SwitchPoint::Frame(frame) => {
+                1u8.hash(state);
+                frame.hash(state);
+            }
+            SwitchPoint::NormalizedTime(t) => {
+                2u8.hash(state);
+                t.to_bits().hash(state);
+            }
+        }
+    }
+}
+
+/// Switch to another animation clip, that waits for the playing animation to reach its [`SwitchPoint`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Reflect)]
+pub struct PendingSwitch {
+    animation_clip: Handle<AnimationClip2D>,
+    at: SwitchPoint,
+}
+
+impl PendingSwitch {
+    /// Handle to the animation clip that is switched to.
+    pub fn animation_clip(&self) -> &Handle<AnimationClip2D> {
+        &self.animation_clip
+    }
+
+    /// Point of the playing animation at which the switch happens.
+    pub fn at(&self) -> SwitchPoint {
+        self.at
+    }
+}
+
+impl PlayingAnimation2D {
+    /// Switch to the animation clip of the pending switch, keeping the repetition behaviour, ping-pong mode and speed.
+    ///
+    /// Returns `false` if there is no pending switch.
+    pub(crate) fn switch(&mut self) -> bool {
+        let Some(pending_switch) = self.pending_switch.take() else {
+            return false;
+        };
+
+        *self = PlayingAnimation2D {
+            repeat: self.repeat,
+            ping_pong: self.ping_pong,
+            speed: self.speed,
+            animation_clip_path: pending_switch.animation_clip.path().cloned(),
+            animation_clip: pending_switch.animation_clip,
+            queue: std::mem::take(&mut self.queue),
+            started_from_queue: std::mem::take(&mut self.started_from_queue),
+            pending_variation: true,
+            ..Default::default()
+        };
+        true
+    }
+
+    /// Switch to the picked animation of the variant group being played, which continues as the same animation.
+    ///
+    /// Returns `false` if no animation was picked.
+    pub(crate) fn switch_variant(&mut self) -> bool {
+        let Some(animation_clip) = self.pending_variant.take() else {
+            return false;
+        };
+
+        *self = PlayingAnimation2D {
+            repeat: self.repeat,
+            ping_pong: self.ping_pong,
+            speed: self.speed,
+            elapsed: self.elapsed,
+            animation_clip_path: animation_clip.path().cloned(),
+            animation_clip,
+            // The repetitions of a variant group count across its animations
+            animation_name: self.animation_name.take(),
+            completions: self.completions,
+            // The cycle completed before the switch is reported by the update continuing in the picked animation
+            stepped_completions: self.completions_this_update,
+            // The animations of a variant group are loaded with their set, so they are ready right away
+            state: self.state,
+            priority: self.priority,
+            lock: self.lock,
+            queue: std::mem::take(&mut self.queue),
+            started_from_queue: std::mem::take(&mut self.started_from_queue),
+            ..Default::default()
+        };
+        true
+    }
+}
+
+impl<T: Default> AnimationPlayer2D<T> {
+    /// Switch to the given animation clip once the playing animation reaches the switch point.
+    ///
+    /// The animation clip keeps the repetition behaviour, ping-pong mode and speed of the playing animation
+    /// and continues with the time left over from the update the switch point was reached in.
+    /// An animation that finishes before reaching the switch point switches right away.
+    ///
+    /// This replaces any other pending switch. If no animation was started yet, the animation clip is started right away.
+    pub fn play_at(&mut self, handle: Handle<AnimationClip2D>, at: SwitchPoint) -> &mut Self {
+        if self.animation.animation_clip == Handle::default()
+            && self.animation.animation_name.is_none()
+        {
+            return self.start(handle);
+        }
+
+        self.animation.pending_switch = Some(PendingSwitch {
+            animation_clip: handle,
+            at,
+        });
+        self
+    }
+
+    /// Switch that waits for the playing animation to reach its switch point, if any.
+    pub fn pending_switch(&self) -> Option<&PendingSwitch> {
+        self.animation.pending_switch.as_ref()
+    }
+
+    /// Cancel the pending switch, returning it if there was one.
+    pub fn cancel_switch(&mut self) -> Option<PendingSwitch> {
+        self.animation.pending_switch.take()
+    }
+}
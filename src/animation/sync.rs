@@ -0,0 +1,96 @@
+//! This module allows to switch between animation clips of different durations, while keeping their playback in sync.
+//!
+
+use bevy::{
+    prelude::{Assets, Handle, Query, Res},
+    reflect::Reflect,
+};
+
+use crate::asset::AnimationClip2D;
+
+use super::{AnimationPlayer2D, ClipState};
+
+/// How the position of the playing animation is mapped into the animation clip switched to.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Reflect)]
+pub enum SyncMode {
+    /// Keep the normalized time, e.g. half way through one animation clip is half way through the other.
+    Normalized,
+    /// Keep the normalized distance to the named sync marker, e.g. "left_foot_down".
+    ///
+    /// Falls back to [`SyncMode::Normalized`] if one of the animation clips does not have the sync marker.
+    Marker(String),
+}
+
+/// Position of the previously playing animation, that is mapped into the animation clip once it is loaded.
+#[derive(Debug, Clone, PartialEq, Reflect)]
+pub(crate) struct PendingSync {
+    pub(crate) from: Handle<AnimationClip2D>,
+    pub(crate) seek_time: f32,
+    pub(crate) mode: SyncMode,
+}
+
+impl PendingSync {
+    /// Seek time in `to` matching the position of the previously playing animation.
+    fn seek_time(&self, from: &AnimationClip2D, to: &AnimationClip2D) -> f32 {
+        let (from_offset, to_offset) = match &self.mode {
+            SyncMode::Marker(name) => from
+                .sync_marker_time(name)
+                .zip(to.sync_marker_time(name))
+                .unwrap_or_default(),
+            SyncMode::Normalized => (0.0, 0.0),
+        };
+
+        let normalized_time =
+            (self.seek_time - from_offset).rem_euclid(from.duration()) / from.duration();
+        (to_offset + normalized_time * to.duration()).rem_euclid(to.duration())
+    }
+}
+
+impl<T: Default> AnimationPlayer2D<T> {
+    /// Start playing an animation, continuing from the position of the playing animation.
+    ///
+    /// Unlike [`Self::play_continue`], this works for animation clips of different durations.
+    /// The position is mapped once both animation clips are loaded.
+    pub fn play_synced(&mut self, handle: Handle<AnimationClip2D>, mode: SyncMode) -> &mut Self {
+        if self.animation.animation_clip != handle || self.paused() {
+            let sync = PendingSync {
+                from: self.animation.animation_clip.clone(),
+                seek_time: self.animation.seek_time,
+                mode,
+            };
+            let elapsed = self.animation.elapsed;
+            self.start_from_time(handle, elapsed, 0.0);
+            self.animation.pending_sync = Some(sync);
+        }
+        self
+    }
+}
+
+/// System that maps the position of the previously playing animation into the animation clip switched to.
+pub(crate) fn sync_animations<T: Default + Send + Sync + 'static>(
+    animation_clips: Res<Assets<AnimationClip2D>>,
+    mut query: Query<&mut AnimationPlayer2D<T>>,
+) {
+    for mut player in &mut query {
+        let animation = &player.animation;
+        let Some(sync) = animation.pending_sync.as_ref() else {
+            continue;
+        };
+        if animation.state == ClipState::Failed {
+            player.animation.pending_sync = None;
+            continue;
+        }
+        let Some(to) = animation_clips.get(&animation.animation_clip) else {
+            continue;
+        };
+
+        let seek_time = match animation_clips.get(&sync.from) {
+            Some(from) => sync.seek_time(from, to),
+            None => 0.0,
+        };
+        // Time accumulated while the animation clip was loading is kept
+        let animation = &mut player.animation;
+        animation.seek_time += seek_time;
+        animation.pending_sync = None;
+    }
+}
@@ -0,0 +1,216 @@
+//! This module keeps animation players in lock-step, by deriving their position from a clock shared by their sync group.
+//!
+
+use std::{marker::PhantomData, time::Duration};
+
+use bevy::{
+    platform::collections::HashMap,
+    prelude::{
+        AssetId, Assets, Component, Query, Reflect, ReflectComponent, ReflectResource, Res, ResMut,
+        Resource,
+    },
+    time::Time,
+};
+
+use crate::asset::AnimationClip2D;
+
+use super::AnimationPlayer2D;
+
+/// Makes the [`AnimationPlayer2D`] of the entity play in lock-step with all other members of the named sync group.
+///
+/// The seek time of each member is derived from the clock of the group, its animation clip and its speed,
+/// so members playing the same animation clip at the same speed share the same phase, no matter when they were spawned.
+/// Paused and finished members are not synchronized until they continue.
+/// If the members are in [`AnimationGroup2D`](super::AnimationGroup2D)s of different speeds,
+/// the clock advances with the fastest speed of its members that are not halted.
+#[derive(Component, Debug, Clone, PartialEq, Eq, Hash, Reflect)]
+#[reflect(Component)]
+pub struct AnimationSyncGroup2D(String);
+
+impl AnimationSyncGroup2D {
+    /// Creates a new AnimationSyncGroup2D
+    pub fn new(name: impl Into<String>) -> Self {
+        Self(name.into())
+    }
+
+    /// Name of the sync group.
+    pub fn name(&self) -> &str {
+        &self.0
+    }
+}
+
+/// Clocks of all sync groups, advanced with [`Time<T>`].
+///
+/// Each group has a clock per animation clip played by its members,
+/// which starts once its first member is synchronized and is dropped once no member plays the animation clip anymore.
+/// A clock only advances while one of its members is not halted, scaled by the fastest speed of the [`AnimationGroup2D`](super::AnimationGroup2D)s of those members.
+#[derive(Debug, Resource, Reflect)]
+#[reflect(Resource)]
+pub struct AnimationSyncClocks<T: Default = ()> {
+    // Durations do not accumulate rounding errors, unlike seconds.
+    clocks: HashMap<(String, AssetId<AnimationClip2D>), Duration>,
+    #[reflect(ignore)]
+    time: PhantomData<T>,
+}
+
+impl<T: Default> Default for AnimationSyncClocks<T> {
+    fn default() -> Self {
+        Self {
+            clocks: HashMap::new(),
+            time: PhantomData,
+        }
+    }
+}
+
+impl<T: Default> AnimationSyncClocks<T> {
+    /// Time in seconds on the clock of the animation clip in the sync group, if it was started.
+    pub fn clock(
+        &self,
+        group: &str,
+        animation_clip: impl Into<AssetId<AnimationClip2D>>,
+    ) -> Option<f32> {
+        self.clocks
+            .get(&(group.to_string(), animation_clip.into()))
+            .map(Duration::as_secs_f32)
+    }
+
+    /// Restart all clocks of the sync group, so all members jump back to the start of their animation clip.
+    pub fn reset(&mut self, group: &str) -> &mut Self {
+        self.clocks.retain(|(name, _), _| name != group);
+        self
+    }
+}
+
+impl<T: Default> AnimationPlayer2D<T> {
+    /// Seek time and speed of the animation at the given time on the clock of its sync group,
+    /// so the animation reaches the position of the clock advanced by `delta` with its next update.
+    fn clock_position(
+        &self,
+        clock: Duration,
+        delta: f32,
+        animation_clip: &AnimationClip2D,
+    ) -> (f32, f32) {
+        let animation = &self.animation;
+        let duration = animation_clip.duration();
+        let clock = clock.as_secs_f64();
+
+        let (seek_time, speed) = if !animation.ping_pong {
+            let seek_time = (clock * animation.speed as f64).rem_euclid(duration as f64);
+            (seek_time as f32, animation.speed)
+        } else {
+            // A ping-pong cycle plays forward and back again
+            let speed = animation.speed.abs();
+            let position = (clock * speed as f64).rem_euclid(2.0 * duration as f64) as f32;
+            if position < duration {
+                (position, speed)
+            } else {
+                (2.0 * duration - position, -speed)
+            }
+        };
+
+        // Snap to the start of the next frame, if the seek time only misses it by a rounding error
+        let next_frame = animation_clip.frame_at(seek_time) + 1;
+        if next_frame < animation_clip.keyframe_timestamps().len() {
+            let frame_start = animation_clip.frame_start(next_frame);
+            if frame_start - seek_time <= FRAME_START_TOLERANCE * duration {
+                return (
+                    land_on_frame_start(frame_start, delta * speed, animation_clip),
+                    speed,
+                );
+            }
+        }
+        (
+            land_on_frame_start(seek_time.min(duration), delta * speed, animation_clip),
+            speed,
+        )
+    }
+}
+
+/// Relative distance to the start of a frame, that is considered a rounding error.
+const FRAME_START_TOLERANCE: f32 = 1e-5;
+
+/// Maximum number of steps to the next representable seek time, when nudging onto the start of a frame.
+const MAX_NUDGES: usize = 64;
+
+/// Nudge the seek time, so adding the distance in the animation update does not miss the start of a frame by a rounding error.
+fn land_on_frame_start(seek_time: f32, distance: f32, animation_clip: &AnimationClip2D) -> f32 {
+    let duration = animation_clip.duration();
+    let landing = seek_time + distance;
+    if duration <= 0.0 || !(0.0..duration).contains(&landing) {
+        return seek_time;
+    }
+    let next_frame = animation_clip.frame_at(landing) + 1;
+    let frame_start = if next_frame < animation_clip.keyframe_timestamps().len() {
+        animation_clip.frame_start(next_frame)
+    } else {
+        duration
+    };
+    if frame_start - landing > FRAME_START_TOLERANCE * duration {
+        return seek_time;
+    }
+
+    // Start at the seek time that lands on the frame start and step over the remaining rounding error
+    let mut nudged = (frame_start - distance).max(seek_time);
+    for _ in 0..MAX_NUDGES {
+        if nudged + distance >= frame_start {
+            return nudged;
+        }
+        let next = nudged.next_up();
+        if next <= nudged {
+            break;
+        }
+        nudged = next;
+    }
+    seek_time
+}
+
+/// System that moves the members of all sync groups to the current position of their clock, before the clocks are advanced.
+pub(crate) fn update_sync_groups<T: Default + Send + Sync + 'static>(
+    time: Res<Time<T>>,
+    animation_clips: Res<Assets<AnimationClip2D>>,
+    mut clocks: ResMut<AnimationSyncClocks<T>>,
+    mut query: Query<(&AnimationSyncGroup2D, &mut AnimationPlayer2D<T>)>,
+) {
+    // Speed of the animation group of the playing members of each clock
+    let mut members = HashMap::new();
+    for (group, mut player) in &mut query {
+        let key = (group.0.clone(), player.animation.animation_clip.id());
+        let clock = *clocks.clocks.entry(key.clone()).or_default();
+        let speed = members.entry(key).or_insert(None);
+        // Halted and finished members keep the clock, so they are synchronized again once they continue
+        if player.halted() {
+            continue;
+        }
+        // The fastest member drives the clock, so the clock speed does not depend on the order of the members
+        *speed = Some(speed.map_or(player.group.speed, |speed: f32| {
+            speed.max(player.group.speed)
+        }));
+        if player.finished() {
+            continue;
+        }
+
+        let Some(animation_clip) = animation_clips
+            .get(&player.animation.animation_clip)
+            .filter(|animation_clip| animation_clip.duration() > 0.0)
+        else {
+            continue;
+        };
+        let delta = time.delta_secs() * player.group.speed;
+        let (seek_time, speed) = player.clock_position(clock, delta, animation_clip);
+        if player.animation.seek_time != seek_time || player.animation.speed != speed {
+            player.animation.seek_time = seek_time;
+            player.animation.speed = speed;
+        }
+    }
+
+    let delta = time.delta();
+    clocks.clocks.retain(|key, clock| {
+        let Some(speed) = members.get(key) else {
+            return false;
+        };
+        if let Some(speed) = speed {
+            *clock += delta.mul_f32(speed.max(0.0));
+        }
+        true
+    });
+}
@@ -0,0 +1,158 @@
+//! This module keeps track of the tempo of a song, so animations can be synchronized with its beats.
+//!
+
+use bevy::prelude::{Reflect, ReflectResource, Resource};
+use thiserror::Error;
+
+/// Tempo and position of the song, that animations with [`AnimationDrive::Tempo`](super::AnimationDrive::Tempo) are synchronized with.
+///
+/// The song position is usually set every frame from the playback position of the audio,
+/// so synchronized animations stay locked to the beats no matter how much the frame time varies.
+/// Songs with a changing tempo are described by tempo changes at their song positions.
+#[derive(Debug, Clone, PartialEq, Resource, Reflect)]
+#[reflect(Resource)]
+pub struct AnimationTempo {
+    /// Song positions in seconds with the tempo from there on, sorted by song position and starting at 0.0.
+    tempo_changes: Vec<(f32, f32)>,
+    beats_per_bar: u32,
+    beat_unit: u32,
+    song_position: f32,
+}
+
+/// Possible errors that can be produced by [`AnimationTempo`]
+#[non_exhaustive]
+#[derive(Debug, Error)]
+pub enum AnimationTempoError {
+    /// Error that occurs, if the time signature has no beats per bar or no beat unit.
+    #[error(
+        "Time signature {0}/{1} is invalid, because it needs at least one beat per bar and a beat unit"
+    )]
+    InvalidTimeSignature(u32, u32),
+}
+
+impl Default for AnimationTempo {
+    fn default() -> Self {
+        Self::new(120.0)
+    }
+}
+
+impl AnimationTempo {
+    /// Creates a new AnimationTempo in 4/4 time, with the song position at its start.
+    pub fn new(bpm: f32) -> Self {
+        Self {
+            tempo_changes: vec![(0.0, bpm)],
+            beats_per_bar: 4,
+            beat_unit: 4,
+            song_position: 0.0,
+        }
+    }
+
+    /// Set the time signature, e.g. `(3, 4)` for a waltz.
+    pub fn with_time_signature(
+        mut self,
+        beats_per_bar: u32,
+        beat_unit: u32,
+    ) -> Result<Self, AnimationTempoError> {
+        if beats_per_bar == 0 || beat_unit == 0 {
+            return Err(AnimationTempoError::InvalidTimeSignature(
+                beats_per_bar,
+                beat_unit,
+            ));
+        }
+
+        self.beats_per_bar = beats_per_bar;
+        self.beat_unit = beat_unit;
+        Ok(self)
+    }
+
+    /// Change the tempo from the given song position on, until the next tempo change.
+    ///
+    /// A tempo change at the same song position is replaced. Changes before the start of the song apply from its start.
+    pub fn with_tempo_change(mut self, song_position: f32, bpm: f32) -> Self {
+        self.add_tempo_change(song_position, bpm);
+        self
+    }
+
+    /// Change the tempo from the given song position on, until the next tempo change.
+    ///
+    /// See also [`Self::with_tempo_change`].
+    pub fn add_tempo_change(&mut self, song_position: f32, bpm: f32) -> &mut Self {
+        let song_position = song_position.max(0.0);
+        let index = self
+            .tempo_changes
+            .partition_point(|(position, _)| *position < song_position);
+        match self.tempo_changes.get_mut(index) {
+            Some(tempo_change) if tempo_change.0 == song_position => tempo_change.1 = bpm,
+            _ => self.tempo_changes.insert(index, (song_position, bpm)),
+        }
+        self
+    }
+
+    /// Change the tempo from the current song position on, until the next tempo change.
+    ///
+    /// The beats before the current song position keep their timing.
+    pub fn set_bpm(&mut self, bpm: f32) -> &mut Self {
+        self.add_tempo_change(self.song_position, bpm)
+    }
+
+    /// Set the position in the song in seconds.
+    pub fn set_song_position(&mut self, seconds: f32) -> &mut Self {
+        self.song_position = seconds;
+        self
+    }
+
+    /// Move the position in the song by the given seconds.
+    pub fn advance(&mut self, seconds: f32) -> &mut Self {
+        self.song_position += seconds;
+        self
+    }
+
+    /// Tempo in beats per minute at the current song position.
+    pub fn bpm(&self) -> f32 {
+        self.tempo_changes
+            .iter()
+            .take_while(|(position, _)| *position <= self.song_position)
+            .last()
+            .or(self.tempo_changes.first())
+            .map_or(0.0, |(_, bpm)| *bpm)
+    }
+
+    /// All tempo changes as song positions in seconds with the tempo from there on, sorted by song position.
+    pub fn tempo_changes(&self) -> &[(f32, f32)] {
+        &self.tempo_changes
+    }
+
+    /// Time signature as beats per bar and the note value of a beat.
+    pub fn time_signature(&self) -> (u32, u32) {
+        (self.beats_per_bar, self.beat_unit)
+    }
+
+    /// Position in the song in seconds.
+    pub fn song_position(&self) -> f32 {
+        self.song_position
+    }
+
+    /// Position in the song in beats.
+    ///
+    /// The beats of all tempo changes up to the current song position are summed up,
+    /// the first tempo also applies before the start of the song.
+    pub fn beat(&self) -> f32 {
+        let mut beat = 0.0;
+        for (i, (start, bpm)) in self.tempo_changes.iter().enumerate() {
+            if i > 0 && self.song_position <= *start {
+                break;
+            }
+            let end = match self.tempo_changes.get(i + 1) {
+                Some((next_start, _)) => self.song_position.min(*next_start),
+                None => self.song_position,
+            };
+            beat += (end - start) * bpm / 60.0;
+        }
+        beat
+    }
+
+    /// Position in the song in bars.
+    pub fn bar(&self) -> f32 {
+        self.beat() / self.beats_per_bar as f32
+    }
+}
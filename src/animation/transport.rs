@@ -0,0 +1,196 @@
+//! This module implements frame level transport controls of an [`AnimationPlayer2D`], e.g. for animation inspectors.
+//!
+
+use bevy::prelude::{DetectChangesMut, Query, Reflect};
+
+use crate::asset::AnimationClip2D;
+
+use super::{AnimationPlayer2D, FrameIndexAnimatable, PlayingAnimation2D};
+
+/// Whether the animation events of the frames crossed by seeking are sent.
+///
+/// See [`AnimationPlayer2D::seek_to_frame`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
+pub enum SeekEvents {
+    /// No animation events are sent, not even for the frame seeked to.
+    #[default]
+    Suppress,
+    /// The animation events of all crossed frames including the frame seeked to are sent, in the order they are crossed.
+    Fire,
+}
+
+impl PlayingAnimation2D {
+    /// Jump to the given seek time, so its frame is reached right away.
+    ///
+    /// With [`SeekEvents::Fire`] the frames from the current one to the new one are crossed in the given direction, wrapping around.
+    pub(crate) fn jump_to(
+        &mut self,
+        seek_time: f32,
+        forward: bool,
+        animation_clip: &AnimationClip2D,
+        seek_events: SeekEvents,
+    ) {
+        let frame = animation_clip.frame_at(seek_time);
+        if seek_events == SeekEvents::Fire {
+            let frames = animation_clip.keyframe_timestamps().len();
+            let mut crossed = self.frame.unwrap_or(frame);
+            if self.frame.is_none() {
+                self.crossed_frames.push((frame, forward));
+            }
+            while crossed != frame {
+                crossed = if forward {
+                    (crossed + 1) % frames
+                } else {
+                    (crossed + frames - 1) % frames
+                };
+                self.crossed_frames.push((crossed, forward));
+            }
+        }
+
+        self.seek_time = seek_time;
+        // The events of the frame are sent from the crossed frames, if at all
+        self.last_frame = Some(frame);
+        self.frame = Some(frame);
+    }
+}
+
+impl<T: Default> AnimationPlayer2D<T> {
+    /// Set whether the animation events of the frames crossed by seeking are sent.
+    pub fn set_seek_events(&mut self, seek_events: SeekEvents) -> &mut Self {
+        self.seek_events = seek_events;
+        self
+    }
+
+    /// Whether the animation events of the frames crossed by seeking are sent.
+    pub fn seek_events(&self) -> SeekEvents {
+        self.seek_events
+    }
+
+    /// Seek to the start of the given frame of the playing animation clip, clamped to its last frame.
+    ///
+    /// The frame is reached right away and its texture atlas index applied with the next animation update, even if paused.
+    /// Use [`Self::apply_frame_index`] to apply it right away as well.
+    pub fn seek_to_frame(&mut self, frame: usize, animation_clip: &AnimationClip2D) -> &mut Self {
+        let frames = animation_clip.keyframe_timestamps().len();
+        let frame = frame.min(frames - 1);
+        let forward = self.animation.frame.is_none_or(|current| current <= frame);
+        self.animation.jump_to(
+            animation_clip.frame_start(frame),
+            forward,
+            animation_clip,
+            self.seek_events,
+        );
+        self
+    }
+
+    /// Seek to the normalized time of the playing animation clip, clamped to the range [0.0, 1.0].
+    ///
+    /// See also [`Self::seek_to_frame`].
+    pub fn seek_to_normalized(&mut self, t: f32, animation_clip: &AnimationClip2D) -> &mut Self {
+        let seek_time = t.clamp(0.0, 1.0) * animation_clip.duration();
+        let forward = seek_time >= self.animation.seek_time;
+        self.animation
+            .jump_to(seek_time, forward, animation_clip, self.seek_events);
+        self
+    }
+
+    /// Step to the next frame of the playing animation clip.
+    ///
+    /// Frames are stepped by their index, regardless of the playback direction,
+    /// so on the return pass of a ping-pong animation this steps against the playback direction.
+    /// Stepping past the last frame completes a cycle and wraps around to the first frame, unless the animation finishes.
+    /// The completed cycle is reported by [`Self::just_finished_cycle`] and [`Self::just_finished`] like in playback.
+    /// See also [`Self::seek_to_frame`].
+    pub fn next_frame(&mut self, animation_clip: &AnimationClip2D) -> &mut Self {
+        let frames = animation_clip.keyframe_timestamps().len();
+        let frame = self.frame();
+        if frame + 1 < frames {
+            self.animation.jump_to(
+                animation_clip.frame_start(frame + 1),
+                true,
+                animation_clip,
+                self.seek_events,
+            );
+        } else if !self.finished() {
+            let animation = &mut self.animation;
+            animation.completions += 1;
+            animation.completions_this_update += 1;
+            // The next animation update would forget the completion otherwise
+            animation.stepped_completions += 1;
+            // A finished animation holds the last frame
+            let seek_time = if self.finished() {
+                animation_clip.duration()
+            } else {
+                animation_clip.frame_start(0)
+            };
+            self.animation
+                .jump_to(seek_time, true, animation_clip, self.seek_events);
+        }
+        self
+    }
+
+    /// Step to the previous frame of the playing animation clip.
+    ///
+    /// Like [`Self::next_frame`], frames are stepped by their index regardless of the playback direction.
+    /// Stepping before the first frame reverts a completed cycle and wraps around to the last frame.
+    /// Stepping back from a finished animation only reverts the cycle that finished it.
+    /// See also [`Self::seek_to_frame`].
+    pub fn previous_frame(&mut self, animation_clip: &AnimationClip2D) -> &mut Self {
+        let frames = animation_clip.keyframe_timestamps().len();
+        let frame = self.frame();
+        let finished = self.finished();
+        if finished {
+            self.animation.completions = self.animation.completions.saturating_sub(1);
+        }
+        if frame > 0 {
+            self.animation.jump_to(
+                animation_clip.frame_start(frame - 1),
+                false,
+                animation_clip,
+                self.seek_events,
+            );
+        } else {
+            if !finished {
+                self.animation.completions = self.animation.completions.saturating_sub(1);
+            }
+            self.animation.jump_to(
+                animation_clip.frame_start(frames - 1),
+                false,
+                animation_clip,
+                self.seek_events,
+            );
+        }
+        self
+    }
+
+    /// Apply the texture atlas index of the current frame to the target right away, e.g. after stepping a paused animation.
+    ///
+    /// Otherwise it is applied with the next animation update.
+    pub fn apply_frame_index(
+        &self,
+        animation_clip: &AnimationClip2D,
+        target: &mut impl FrameIndexAnimatable,
+    ) -> &Self {
+        if let Some(index) = animation_clip.keyframes().get(self.frame())
+            && let Some(texture_atlas_index) = target.get_frame_index_mut()
+        {
+            *texture_atlas_index = index;
+        }
+        self
+    }
+}
+
+/// System that forgets the frames crossed since the last animation events were sent.
+pub(crate) fn clear_crossed_frames<T: Default + Send + Sync + 'static>(
+    mut query: Query<&mut AnimationPlayer2D<T>>,
+) {
+    for mut player in &mut query {
+        if !player.animation.crossed_frames.is_empty()
+            || !player.animation.suppressed_events.is_empty()
+        {
+            let animation = &mut player.bypass_change_detection().animation;
+            animation.crossed_frames.clear();
+            animation.suppressed_events.clear();
+        }
+    }
+}
@@ -0,0 +1,110 @@
+//! This module varies the start offset and speed of animations, so identical entities spawned together don't animate in unison.
+//!
+
+use bevy::prelude::{Component, DetectChangesMut, Entity, Query, Reflect, ReflectComponent};
+
+use crate::rng::SplitMix64;
+
+use super::AnimationPlayer2D;
+
+/// Randomizes the start offset and speed of the [`AnimationPlayer2D`] of the entity,
+/// whenever an animation clip is (re)started from the beginning, e.g. with [`AnimationPlayer2D::start`], [`AnimationPlayer2D::play_named`],
+/// from the queue, by a switch or by returning to the resting animation.
+/// The animations of a variant group only vary once the group is started, not for each picked variant.
+///
+/// The random numbers are seeded with the entity, unless a seed is set, so the variation is deterministic.
+/// They are drawn by the player, so restoring an [`AnimationPlayer2DSnapshot`](super::AnimationPlayer2DSnapshot) rewinds them as well.
+/// The seed is used once the first animation varies, changing it later has no effect on the player.
+#[derive(Component, Debug, Clone, PartialEq, Reflect)]
+#[reflect(Component)]
+pub struct AnimationVariation2D {
+    max_offset: f32,
+    speed_range: (f32, f32),
+    seed: Option<u64>,
+}
+
+impl Default for AnimationVariation2D {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl AnimationVariation2D {
+    /// Creates a new AnimationVariation2D, that starts anywhere in the animation clip at normal speed.
+    pub fn new() -> Self {
+        Self {
+            max_offset: 1.0,
+            speed_range: (1.0, 1.0),
+            seed: None,
+        }
+    }
+
+    /// Set the maximum start offset as normalized time of the animation clip, clamped to the range [0.0, 1.0].
+    pub fn with_max_offset(mut self, max_offset: f32) -> Self {
+        self.max_offset = max_offset.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Set the range the speed of the animation is multiplied with.
+    pub fn with_speed_range(mut self, min: f32, max: f32) -> Self {
+        self.speed_range = (min, max);
+        self
+    }
+
+    /// Set the seed of the random numbers.
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.seed = Some(seed);
+        self
+    }
+
+    /// Maximum start offset as normalized time of the animation clip.
+    pub fn max_offset(&self) -> f32 {
+        self.max_offset
+    }
+
+    /// Range the speed of the animation is multiplied with.
+    pub fn speed_range(&self) -> (f32, f32) {
+        self.speed_range
+    }
+
+    /// Seed of the random numbers, if set.
+    pub fn seed(&self) -> Option<u64> {
+        self.seed
+    }
+}
+
+/// System that applies a random start offset and speed to animations started since the last update.
+pub(crate) fn vary_started_animations<T: Default + Send + Sync + 'static>(
+    mut query: Query<(
+        Entity,
+        Option<&AnimationVariation2D>,
+        &mut AnimationPlayer2D<T>,
+    )>,
+) {
+    for (entity, variation, mut player) in &mut query {
+        if !player.animation.pending_variation {
+            continue;
+        }
+        // Animations started without a variation are not varied once it is added later
+        let Some(variation) = variation else {
+            player.bypass_change_detection().animation.pending_variation = false;
+            continue;
+        };
+        // The offset depends on the duration, so wait until the animation clip is loaded
+        let Some(duration) = player.animation.duration else {
+            continue;
+        };
+
+        let rng = player
+            .variation_rng
+            .get_or_insert_with(|| SplitMix64::new(variation.seed.unwrap_or(entity.to_bits())));
+        let offset = rng.next_f32() * variation.max_offset;
+        let (min, max) = variation.speed_range;
+        let speed = rng.range(min, max);
+
+        let animation = &mut player.animation;
+        animation.pending_variation = false;
+        animation.seek_time += offset * duration;
+        animation.speed *= speed;
+    }
+}
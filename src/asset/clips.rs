@@ -0,0 +1,84 @@
+//! This module defines typed names for the animation clips of an [`AnimationClip2DSet`].
+//!
+
+use bevy::{asset::AssetServer, prelude::Handle};
+use thiserror::Error;
+
+use super::{AnimationClip2D, AnimationClip2DSet};
+
+/// Typed names of the animation clips in a trickfilm file.
+///
+/// Usually derived for an enum:
+/// ```rust, ignore
+/// #[derive(AnimationClips)]
+/// #[trickfilm(path = "gabe.trickfilm.ron")]
+/// enum Gabe {
+///     Idle,
+///     #[clip("run")]
+///     Run,
+/// }
+/// ```
+pub trait AnimationClips: Send + Sync + 'static {
+    /// Asset path of the trickfilm file.
+    const PATH: &'static str;
+
+    /// Names of all animation clips.
+    const NAMES: &'static [&'static str];
+
+    /// Name of this animation clip in the [`AnimationClip2DSet`].
+    fn name(&self) -> &'static str;
+
+    /// Load the [`AnimationClip2DSet`] of the trickfilm file.
+    fn load_set(asset_server: &AssetServer) -> Handle<AnimationClip2DSet> {
+        asset_server.load(Self::PATH)
+    }
+
+    /// Load this animation clip.
+    fn load(&self, asset_server: &AssetServer) -> Handle<AnimationClip2D> {
+        asset_server.load(format!("{}#{}", Self::PATH, self.name()))
+    }
+
+    /// Handle to this animation clip in the loaded [`AnimationClip2DSet`].
+    ///
+    /// Variant groups have no handle, because they are resolved when played.
+    fn handle<'a>(&self, set: &'a AnimationClip2DSet) -> Option<&'a Handle<AnimationClip2D>> {
+        set.animations.get(self.name())
+    }
+
+    /// Check that every animation clip exists in the loaded [`AnimationClip2DSet`].
+    fn validate(set: &AnimationClip2DSet) -> Result<(), MissingAnimationClips> {
+        let names: Vec<&'static str> = Self::NAMES
+            .iter()
+            .filter(|name| {
+                !set.animations.contains_key(**name) && !set.variants.contains_key(**name)
+            })
+            .copied()
+            .collect();
+
+        if names.is_empty() {
+            Ok(())
+        } else {
+            Err(MissingAnimationClips {
+                path: Self::PATH,
+                names,
+            })
+        }
+    }
+}
+
+/// Error that occurs, if animation clips of [`AnimationClips`] do not exist in the [`AnimationClip2DSet`].
+#[derive(Debug, Clone, Error)]
+#[error("Animation clips {names:?} do not exist in {path}")]
+pub struct MissingAnimationClips {
+    /// Asset path of the trickfilm file.
+    pub path: &'static str,
+    /// Names of the missing animation clips.
+    pub names: Vec<&'static str>,
+}
+
+/// Test helper that panics, if any animation clip of `C` does not exist in the loaded [`AnimationClip2DSet`].
+pub fn assert_animation_clips<C: AnimationClips>(set: &AnimationClip2DSet) {
+    if let Err(error) = C::validate(set) {
+        panic!("{error}");
+    }
+}
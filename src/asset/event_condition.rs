@@ -0,0 +1,67 @@
+//! This module defines conditions, that decide whether an animation event is sent when its frame is reached.
+//!
+
+use serde::Deserialize;
+
+/// Condition of an animation event on a frame of an [`AnimationClip2D`](super::AnimationClip2D).
+///
+/// All given conditions need to be met for the event to be sent.
+/// Like the animation events themselves, conditions only apply to players of the default time, i.e. [`AnimationPlayer2D<()>`](crate::animation::AnimationPlayer2D).
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct AnimationEventCondition {
+    /// Probability in the range [0.0, 1.0], that the event is sent each time the frame is reached.
+    #[serde(default = "default_probability")]
+    pub probability: f32,
+    /// Only send the event on the given cycle of the animation, starting with 0 for the first cycle.
+    #[serde(default)]
+    pub on_loop: Option<u32>,
+    /// Only send the event on every nth cycle of the animation, starting with the first cycle.
+    #[serde(default)]
+    pub every_nth_loop: Option<u32>,
+    /// Only send the event if the frame is reached playing forward.
+    #[serde(default)]
+    pub forward_only: bool,
+    /// Type path of a component, that the entity of the [`AnimationPlayer2D`](crate::animation::AnimationPlayer2D) needs to have.
+    ///
+    /// The component needs to be registered with the type registry.
+    #[serde(default)]
+    pub marker: Option<String>,
+}
+
+impl Default for AnimationEventCondition {
+    fn default() -> Self {
+        Self {
+            probability: default_probability(),
+            on_loop: None,
+            every_nth_loop: None,
+            forward_only: false,
+            marker: None,
+        }
+    }
+}
+
+fn default_probability() -> f32 {
+    1.0
+}
+
+impl AnimationEventCondition {
+    /// Checks that the condition can be met.
+    pub(crate) fn validate(&self) -> Result<(), &'static str> {
+        if !(0.0..=1.0).contains(&self.probability) {
+            return Err("its probability is not in the range [0.0, 1.0]");
+        }
+        if self.every_nth_loop == Some(0) {
+            return Err("it can not be sent every 0th loop");
+        }
+        Ok(())
+    }
+
+    /// Check if the cycle and direction of the animation meet the condition.
+    pub(crate) fn matches(&self, completions: u32, forward: bool) -> bool {
+        (forward || !self.forward_only)
+            && self.on_loop.is_none_or(|cycle| completions == cycle)
+            && self
+                .every_nth_loop
+                .is_none_or(|n| completions.is_multiple_of(n))
+    }
+}
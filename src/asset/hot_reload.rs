@@ -0,0 +1,70 @@
+//! This module reports changes of the animation clips of hot reloaded [`AnimationClip2DSet`]s.
+//!
+
+use bevy::{
+    platform::collections::{HashMap, HashSet},
+    prelude::{
+        AssetEvent, AssetId, Assets, Event, EventReader, EventWriter, Res, ResMut, Resource,
+    },
+};
+
+use super::AnimationClip2DSet;
+
+/// Sent when a hot reloaded [`AnimationClip2DSet`] gained or lost animation clips.
+#[derive(Debug, Clone, Event)]
+pub struct AnimationClip2DSetChanged {
+    /// The animation clip set that changed.
+    pub id: AssetId<AnimationClip2DSet>,
+    /// Names of the animation clips that were added.
+    pub added: Vec<String>,
+    /// Names of the animation clips that were removed.
+    pub removed: Vec<String>,
+}
+
+/// Names of the animation clips of each loaded [`AnimationClip2DSet`] before it was reloaded.
+#[derive(Debug, Default, Resource)]
+pub(crate) struct AnimationClip2DSetLabels(HashMap<AssetId<AnimationClip2DSet>, HashSet<String>>);
+
+/// System that compares the animation clips of modified [`AnimationClip2DSet`]s to their previous state.
+pub(crate) fn send_animation_clip_set_changes(
+    mut labels: ResMut<AnimationClip2DSetLabels>,
+    mut asset_events: EventReader<AssetEvent<AnimationClip2DSet>>,
+    animation_clip_sets: Res<Assets<AnimationClip2DSet>>,
+    mut changed_events: EventWriter<AnimationClip2DSetChanged>,
+) {
+    for asset_event in asset_events.read() {
+        match asset_event {
+            AssetEvent::Added { id }
+            | AssetEvent::Modified { id }
+            | AssetEvent::LoadedWithDependencies { id } => {
+                let Some(animation_clip_set) = animation_clip_sets.get(*id) else {
+                    continue;
+                };
+
+                let new_labels: HashSet<String> =
+                    animation_clip_set.animations.keys().cloned().collect();
+                let Some(old_labels) = labels.0.insert(*id, new_labels.clone()) else {
+                    continue;
+                };
+
+                let mut added: Vec<String> = new_labels.difference(&old_labels).cloned().collect();
+                let mut removed: Vec<String> =
+                    old_labels.difference(&new_labels).cloned().collect();
+                if added.is_empty() && removed.is_empty() {
+                    continue;
+                }
+                added.sort_unstable();
+                removed.sort_unstable();
+
+                changed_events.write(AnimationClip2DSetChanged {
+                    id: *id,
+                    added,
+                    removed,
+                });
+            }
+            AssetEvent::Removed { id } | AssetEvent::Unused { id } => {
+                labels.0.remove(id);
+            }
+        }
+    }
+}
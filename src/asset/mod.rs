@@ -10,15 +10,35 @@ use std::ops::Range;
 use ::serde::Deserialize;
 use bevy::{
     platform::collections::HashMap,
-    prelude::{App, Asset, AssetApp, Handle, Plugin},
+    prelude::{App, Asset, AssetApp, Handle, Plugin, PostUpdate},
     reflect::{PartialReflect, TypePath},
 };
 use thiserror::Error;
 
-use self::asset_loader::Animation2DLoader;
+use crate::{animation::FinishBehaviour, rng::SplitMix64};
+
+use self::{
+    asset_loader::Animation2DLoader,
+    hot_reload::{AnimationClip2DSetLabels, send_animation_clip_set_changes},
+    state_machine::AnimationStateMachine2DLoader,
+};
 
 pub mod asset_loader;
+mod clips;
+mod event_condition;
+mod hot_reload;
 mod serde;
+mod state_machine;
+mod time_curve;
+
+pub use clips::{AnimationClips, MissingAnimationClips, assert_animation_clips};
+pub use event_condition::AnimationEventCondition;
+pub use hot_reload::AnimationClip2DSetChanged;
+pub use state_machine::{
+    AnimationCondition, AnimationParameter, AnimationState2D, AnimationStateMachine2D,
+    AnimationStateMachine2DError, AnimationStateMachine2DLoaderError, AnimationTransition2D,
+};
+pub use time_curve::TimeCurve;
 
 /// Adds support for spritesheet animation manifest files loading to the app.
 pub struct Animation2DLoaderPlugin;
@@ -27,7 +47,12 @@ impl Plugin for Animation2DLoaderPlugin {
     fn build(&self, app: &mut App) {
         app.init_asset::<AnimationClip2D>()
             .init_asset::<AnimationClip2DSet>()
-            .init_asset_loader::<Animation2DLoader>();
+            .init_asset::<AnimationStateMachine2D>()
+            .init_asset_loader::<Animation2DLoader>()
+            .init_asset_loader::<AnimationStateMachine2DLoader>()
+            .init_resource::<AnimationClip2DSetLabels>()
+            .add_event::<AnimationClip2DSetChanged>()
+            .add_systems(PostUpdate, send_animation_clip_set_changes);
     }
 }
 
@@ -91,6 +116,11 @@ pub struct AnimationClip2D {
     /// Total duration of this animation clip in seconds.
     duration: f32,
     events: HashMap<usize, Vec<Box<dyn PartialReflect>>>,
+    event_conditions: HashMap<(usize, usize), AnimationEventCondition>,
+    sync_markers: HashMap<String, usize>,
+    finish_behaviour: Option<FinishBehaviour>,
+    time_curve: TimeCurve,
+    beats: Option<f32>,
 }
 
 /// Possible errors that can be produced by [`AnimationClip2D`]
@@ -109,6 +139,18 @@ pub enum AnimationClip2DError {
     /// Error that occurs, if an events references a frame outside the frame range.
     #[error("Frame {0} for this animation clip, because it only has {1} frames")]
     InvalidFrame(usize, usize),
+    /// Error that occurs, if a sync marker references a frame outside the frame range.
+    #[error("Sync marker {0} references frame {1}, but the animation clip only has {2} frames")]
+    InvalidSyncMarker(String, usize, usize),
+    /// Error that occurs, if the points of a piecewise time curve do not describe a valid curve.
+    #[error("Time curve is invalid, because {0}")]
+    InvalidTimeCurve(&'static str),
+    /// Error that occurs, if the length of an animation clip in beats is not positive.
+    #[error("Length of {0} beats is not positive")]
+    InvalidBeats(f32),
+    /// Error that occurs, if the condition of an event on a frame does not exist or can not be met.
+    #[error("Condition of event {1} on frame {0} is invalid, because {2}")]
+    InvalidEventCondition(usize, usize, &'static str),
 }
 
 impl AnimationClip2D {
@@ -170,9 +212,88 @@ impl AnimationClip2D {
             keyframes,
             duration,
             events,
+            event_conditions: HashMap::new(),
+            sync_markers: HashMap::new(),
+            finish_behaviour: None,
+            time_curve: TimeCurve::Linear,
+            beats: None,
         })
     }
 
+    /// Sets what happens once an animation of this [`AnimationClip2D`] finished.
+    ///
+    /// This can be overridden per player with [`AnimationPlayer2D::set_finish_behaviour`](crate::animation::AnimationPlayer2D::set_finish_behaviour).
+    pub fn with_finish_behaviour(mut self, finish_behaviour: FinishBehaviour) -> Self {
+        self.finish_behaviour = Some(finish_behaviour);
+        self
+    }
+
+    /// Adds conditions to the events of the [`AnimationClip2D`], identified by their frame and index into the events of the frame.
+    ///
+    /// Events without a condition are always sent.
+    pub fn with_event_conditions(
+        mut self,
+        event_conditions: HashMap<(usize, usize), AnimationEventCondition>,
+    ) -> Result<Self, AnimationClip2DError> {
+        for ((frame, index), condition) in &event_conditions {
+            let events = self.events.get(frame).map_or(0, Vec::len);
+            if *index >= events {
+                return Err(AnimationClip2DError::InvalidEventCondition(
+                    *frame,
+                    *index,
+                    "there is no such event",
+                ));
+            }
+            condition.validate().map_err(|reason| {
+                AnimationClip2DError::InvalidEventCondition(*frame, *index, reason)
+            })?;
+        }
+
+        self.event_conditions = event_conditions;
+        Ok(self)
+    }
+
+    /// Adds named sync markers to the [`AnimationClip2D`], each referencing a frame.
+    ///
+    /// See [`SyncMode::Marker`](crate::animation::SyncMode::Marker).
+    pub fn with_sync_markers(
+        mut self,
+        sync_markers: HashMap<String, usize>,
+    ) -> Result<Self, AnimationClip2DError> {
+        let keyframes_len = self.keyframes.len();
+        if let Some((name, frame)) = sync_markers
+            .iter()
+            .find(|(_, frame)| **frame >= keyframes_len)
+        {
+            return Err(AnimationClip2DError::InvalidSyncMarker(
+                name.clone(),
+                *frame,
+                keyframes_len,
+            ));
+        }
+
+        self.sync_markers = sync_markers;
+        Ok(self)
+    }
+
+    /// Remaps the playback of the [`AnimationClip2D`] with a [`TimeCurve`], e.g. to ease in and out.
+    pub fn with_time_curve(mut self, time_curve: TimeCurve) -> Result<Self, AnimationClip2DError> {
+        time_curve.validate()?;
+        self.time_curve = time_curve;
+        Ok(self)
+    }
+
+    /// Sets the length of a cycle of the [`AnimationClip2D`] in beats, when played locked to the tempo.
+    ///
+    /// See [`AnimationDrive::Tempo`](crate::animation::AnimationDrive::Tempo).
+    pub fn with_beats(mut self, beats: f32) -> Result<Self, AnimationClip2DError> {
+        if beats.is_nan() || beats <= 0.0 {
+            return Err(AnimationClip2DError::InvalidBeats(beats));
+        }
+        self.beats = Some(beats);
+        Ok(self)
+    }
+
     /// Timestamps for each keyframe in seconds.
     #[inline]
     pub fn keyframe_timestamps(&self) -> &[f32] {
@@ -196,6 +317,91 @@ impl AnimationClip2D {
     pub fn events(&self) -> &HashMap<usize, Vec<Box<dyn PartialReflect>>> {
         &self.events
     }
+
+    /// Conditions of the events of this animation clip identified by their associated frame and index into the events of the frame.
+    #[inline]
+    pub fn event_conditions(&self) -> &HashMap<(usize, usize), AnimationEventCondition> {
+        &self.event_conditions
+    }
+
+    /// Condition of the event with the given index on the given frame, if any.
+    #[inline]
+    pub fn event_condition(&self, frame: usize, index: usize) -> Option<&AnimationEventCondition> {
+        self.event_conditions.get(&(frame, index))
+    }
+
+    /// Named sync markers of this animation clip identified by their associated frame.
+    #[inline]
+    pub fn sync_markers(&self) -> &HashMap<String, usize> {
+        &self.sync_markers
+    }
+
+    /// Seek time at which the frame of the named sync marker starts.
+    #[inline]
+    pub fn sync_marker_time(&self, name: &str) -> Option<f32> {
+        self.sync_markers
+            .get(name)
+            .map(|frame| self.frame_start(*frame))
+    }
+
+    /// What happens once an animation of this animation clip finished, if set.
+    #[inline]
+    pub fn finish_behaviour(&self) -> Option<FinishBehaviour> {
+        self.finish_behaviour
+    }
+
+    /// Length of a cycle of this animation clip in beats, if authored in beats.
+    #[inline]
+    pub fn beats(&self) -> Option<f32> {
+        self.beats
+    }
+
+    /// Time curve that remaps the playback of this animation clip.
+    #[inline]
+    pub fn time_curve(&self) -> &TimeCurve {
+        &self.time_curve
+    }
+
+    /// Time in seconds on the keyframe timestamps, that is displayed at the given seek time according to the time curve.
+    #[inline]
+    pub fn sample_time(&self, seek_time: f32) -> f32 {
+        match self.time_curve {
+            TimeCurve::Linear => seek_time,
+            _ if self.duration > 0.0 => {
+                self.time_curve.sample(seek_time / self.duration) * self.duration
+            }
+            _ => seek_time,
+        }
+    }
+
+    /// Frame that is displayed at the given seek time.
+    ///
+    /// This is an index into [`Self::keyframes`].
+    #[inline]
+    pub fn frame_at(&self, seek_time: f32) -> usize {
+        let sample_time = self.sample_time(seek_time);
+        self.keyframe_timestamps
+            .partition_point(|timestamp| *timestamp <= sample_time)
+            .saturating_sub(1)
+    }
+
+    /// Seek time at which the given frame starts to be displayed according to the time curve.
+    ///
+    /// # Panics
+    /// Panics if the frame is out of bounds.
+    pub fn frame_start(&self, frame: usize) -> f32 {
+        let timestamp = self.keyframe_timestamps[frame];
+        if self.time_curve == TimeCurve::Linear || self.duration <= 0.0 {
+            return timestamp;
+        }
+
+        let mut seek_time = self.time_curve.inverse(timestamp / self.duration) * self.duration;
+        // Make sure rounding errors of the inverse curve don't end up in the previous frame
+        while self.frame_at(seek_time) < frame && seek_time < self.duration {
+            seek_time = seek_time.next_up();
+        }
+        seek_time
+    }
 }
 
 /// Set(Map) of AnimationClips for a 2D animation.
@@ -203,4 +409,29 @@ impl AnimationClip2D {
 pub struct AnimationClip2DSet {
     /// Named animations loaded from the trickfilm file.
     pub animations: HashMap<String, Handle<AnimationClip2D>>,
+    /// Named variant groups loaded from the trickfilm file, each with the names of its animations and their weights.
+    ///
+    /// Playing a variant group by name picks one of its animations by weight, every time it is started or repeats.
+    pub variants: HashMap<String, Vec<(String, f32)>>,
+}
+
+impl AnimationClip2DSet {
+    /// Pick an animation of the named variant group by weight.
+    pub(crate) fn pick_variant(
+        &self,
+        name: &str,
+        rng: &mut SplitMix64,
+    ) -> Option<&Handle<AnimationClip2D>> {
+        let variants = self.variants.get(name)?;
+        let total: f32 = variants.iter().map(|(_, weight)| weight).sum();
+        let mut pick = rng.next_f32() * total;
+        let (animation, _) = variants
+            .iter()
+            .find(|(_, weight)| {
+                pick -= weight;
+                pick < 0.0
+            })
+            .or(variants.last())?;
+        self.animations.get(animation)
+    }
 }
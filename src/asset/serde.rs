@@ -3,7 +3,7 @@ use bevy::{
     platform::collections::{HashMap, HashSet},
     reflect::{
         PartialReflect, TypeRegistry,
-        serde::{ReflectDeserializer, TypeRegistrationDeserializer, TypedReflectDeserializer},
+        serde::{TypeRegistrationDeserializer, TypedReflectDeserializer},
     },
 };
 use serde::{
@@ -11,7 +11,9 @@ use serde::{
     de::{DeserializeSeed, Error, Visitor},
 };
 
-use super::{AnimationClip2D, AnimationClip2DSet, Keyframes};
+use crate::animation::FinishBehaviour;
+
+use super::{AnimationClip2D, AnimationClip2DSet, AnimationEventCondition, Keyframes, TimeCurve};
 
 pub struct AnimationClip2DSetDeserializer<'a, 'l> {
     pub type_registry: &'a TypeRegistry,
@@ -52,16 +54,58 @@ impl<'de> Visitor<'de> for AnimationClip2DSetMapVisitor<'_, '_> {
         A: serde::de::MapAccess<'de>,
     {
         let mut value = HashMap::new();
+        let mut variants = HashMap::new();
 
         while let Some(name) = map.next_key::<String>()? {
-            let clip = map.next_value_seed(AnimationClip2DDeserializer {
+            let entry = map.next_value_seed(AnimationClip2DDeserializer {
                 type_registry: self.type_registry,
             })?;
-            let asset = self.load_context.add_labeled_asset(name.clone(), clip);
-            value.insert(name, asset);
+            // An animation clip and a variant group can not share a name, the labeled animation clip would be orphaned
+            match entry {
+                AnimationClip2DEntry::Clip(clip) => {
+                    if variants.contains_key(&name) {
+                        return Err(Error::custom(format_args!(
+                            "`{name}` is both an animation clip and a variant group"
+                        )));
+                    }
+                    let asset = self.load_context.add_labeled_asset(name.clone(), clip);
+                    value.insert(name, asset);
+                }
+                AnimationClip2DEntry::Variants(weighted) => {
+                    if value.contains_key(&name) {
+                        return Err(Error::custom(format_args!(
+                            "`{name}` is both an animation clip and a variant group"
+                        )));
+                    }
+                    variants.insert(name, weighted);
+                }
+            }
+        }
+
+        for (name, weighted) in &variants {
+            if weighted.is_empty() {
+                return Err(Error::custom(format_args!(
+                    "variant group `{name}` has no variants"
+                )));
+            }
+            for (variant, weight) in weighted {
+                if !value.contains_key(variant) {
+                    return Err(Error::custom(format_args!(
+                        "variant `{variant}` of variant group `{name}` is not an animation clip"
+                    )));
+                }
+                if !weight.is_finite() || *weight <= 0.0 {
+                    return Err(Error::custom(format_args!(
+                        "variant `{variant}` of variant group `{name}` has weight {weight}, which is not positive"
+                    )));
+                }
+            }
         }
 
-        Ok(AnimationClip2DSet { animations: value })
+        Ok(AnimationClip2DSet {
+            animations: value,
+            variants,
+        })
     }
 }
 
@@ -76,6 +120,22 @@ enum AnimationClip2DField {
     Duration,
     #[serde(rename = "events")]
     Events,
+    #[serde(rename = "sync_markers")]
+    SyncMarkers,
+    #[serde(rename = "on_finish")]
+    OnFinish,
+    #[serde(rename = "time_curve")]
+    TimeCurve,
+    #[serde(rename = "beats")]
+    Beats,
+    #[serde(rename = "variants")]
+    Variants,
+}
+
+/// Entry of an [`AnimationClip2DSet`], either an animation clip or a variant group.
+enum AnimationClip2DEntry {
+    Clip(AnimationClip2D),
+    Variants(Vec<(String, f32)>),
 }
 
 struct AnimationClip2DDeserializer<'a> {
@@ -83,7 +143,7 @@ struct AnimationClip2DDeserializer<'a> {
 }
 
 impl<'de> DeserializeSeed<'de> for AnimationClip2DDeserializer<'_> {
-    type Value = AnimationClip2D;
+    type Value = AnimationClip2DEntry;
 
     fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
     where
@@ -94,9 +154,25 @@ impl<'de> DeserializeSeed<'de> for AnimationClip2DDeserializer<'_> {
         /* optional keyframe_timestamps of type Vec<usize> */
         /* mandatory duration of type f32 */
         /* optional events of type Box<dyn Reflect> -> use type_registry to reflect the information */
+        /*   each listed event with an optional condition of type AnimationEventCondition */
+        /* optional sync_markers of type HashMap<String, usize> */
+        /* optional on_finish of type FinishBehaviour */
+        /* optional time_curve of type TimeCurve */
+        /* optional beats of type f32 */
+        /* or only variants of type Vec<(String, f32)> for a variant group */
         deserializer.deserialize_struct(
             "AnimationClip2D",
-            &["keyframe_timestamps", "keyframes", "duration", "events"],
+            &[
+                "keyframe_timestamps",
+                "keyframes",
+                "duration",
+                "events",
+                "sync_markers",
+                "on_finish",
+                "time_curve",
+                "beats",
+                "variants",
+            ],
             AnimationClip2DVisitor {
                 type_registry: self.type_registry,
             },
@@ -109,7 +185,7 @@ struct AnimationClip2DVisitor<'a> {
 }
 
 impl<'de> Visitor<'de> for AnimationClip2DVisitor<'_> {
-    type Value = AnimationClip2D;
+    type Value = AnimationClip2DEntry;
 
     fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
         formatter.write_str("struct of animation 2d clip")
@@ -123,6 +199,11 @@ impl<'de> Visitor<'de> for AnimationClip2DVisitor<'_> {
         let mut keyframe_timestamps = None;
         let mut duration = None;
         let mut events = None;
+        let mut sync_markers = None;
+        let mut on_finish = None;
+        let mut time_curve = None;
+        let mut beats = None;
+        let mut variants = None;
 
         while let Some(key) = map.next_key()? {
             match key {
@@ -152,14 +233,76 @@ impl<'de> Visitor<'de> for AnimationClip2DVisitor<'_> {
                         type_registry: self.type_registry,
                     })?);
                 }
+                AnimationClip2DField::SyncMarkers => {
+                    if sync_markers.is_some() {
+                        return Err(Error::duplicate_field("sync_markers"));
+                    }
+                    sync_markers = Some(map.next_value::<HashMap<String, usize>>()?);
+                }
+                AnimationClip2DField::OnFinish => {
+                    if on_finish.is_some() {
+                        return Err(Error::duplicate_field("on_finish"));
+                    }
+                    on_finish = Some(map.next_value::<FinishBehaviour>()?);
+                }
+                AnimationClip2DField::TimeCurve => {
+                    if time_curve.is_some() {
+                        return Err(Error::duplicate_field("time_curve"));
+                    }
+                    time_curve = Some(map.next_value::<TimeCurve>()?);
+                }
+                AnimationClip2DField::Beats => {
+                    if beats.is_some() {
+                        return Err(Error::duplicate_field("beats"));
+                    }
+                    beats = Some(map.next_value::<f32>()?);
+                }
+                AnimationClip2DField::Variants => {
+                    if variants.is_some() {
+                        return Err(Error::duplicate_field("variants"));
+                    }
+                    variants = Some(map.next_value::<Vec<(String, f32)>>()?);
+                }
             }
         }
 
+        if let Some(variants) = variants {
+            let is_clip = keyframes.is_some()
+                || keyframe_timestamps.is_some()
+                || duration.is_some()
+                || events.is_some()
+                || sync_markers.is_some()
+                || on_finish.is_some()
+                || time_curve.is_some()
+                || beats.is_some();
+            if is_clip {
+                return Err(Error::custom(
+                    "variants can not be combined with the fields of an animation clip",
+                ));
+            }
+            return Ok(AnimationClip2DEntry::Variants(variants));
+        }
+
         let keyframes = keyframes.ok_or_else(|| Error::missing_field("keyframes"))?;
         let duration = duration.ok_or_else(|| Error::missing_field("duration"))?;
 
-        AnimationClip2D::new(keyframe_timestamps, keyframes, duration, events)
-            .map_err(Error::custom)
+        let (events, event_conditions) = match events {
+            Some((events, event_conditions)) => (Some(events), event_conditions),
+            None => (None, HashMap::new()),
+        };
+        let clip = AnimationClip2D::new(keyframe_timestamps, keyframes, duration, events)
+            .and_then(|clip| clip.with_event_conditions(event_conditions))
+            .and_then(|clip| clip.with_sync_markers(sync_markers.unwrap_or_default()))
+            .and_then(|clip| clip.with_time_curve(time_curve.unwrap_or_default()))
+            .and_then(|clip| match beats {
+                Some(beats) => clip.with_beats(beats),
+                None => Ok(clip),
+            })
+            .map_err(Error::custom)?;
+        Ok(AnimationClip2DEntry::Clip(match on_finish {
+            Some(finish_behaviour) => clip.with_finish_behaviour(finish_behaviour),
+            None => clip,
+        }))
     }
 }
 
@@ -167,8 +310,14 @@ struct AnimationEventsMapDeserializer<'a> {
     pub type_registry: &'a TypeRegistry,
 }
 
+/// Events of an animation clip per frame, and the conditions of events identified by their frame and index.
+type AnimationEventsMap = (
+    HashMap<usize, Vec<Box<dyn PartialReflect>>>,
+    HashMap<(usize, usize), AnimationEventCondition>,
+);
+
 impl<'de> DeserializeSeed<'de> for AnimationEventsMapDeserializer<'_> {
-    type Value = HashMap<usize, Vec<Box<dyn PartialReflect>>>;
+    type Value = AnimationEventsMap;
 
     fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
     where
@@ -185,7 +334,7 @@ struct AnimationEventsMapVisitor<'a> {
 }
 
 impl<'de> Visitor<'de> for AnimationEventsMapVisitor<'_> {
-    type Value = HashMap<usize, Vec<Box<dyn PartialReflect>>>;
+    type Value = AnimationEventsMap;
 
     fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
         formatter.write_str("map of events")
@@ -196,15 +345,23 @@ impl<'de> Visitor<'de> for AnimationEventsMapVisitor<'_> {
         A: serde::de::MapAccess<'de>,
     {
         let mut value = HashMap::new();
+        let mut conditions = HashMap::new();
 
         while let Some(frame) = map.next_key::<usize>()? {
-            let events = map.next_value_seed(AnimationEventsDeserializer {
+            let entries = map.next_value_seed(AnimationEventsDeserializer {
                 type_registry: self.type_registry,
             })?;
+            let mut events = Vec::with_capacity(entries.len());
+            for (index, (event, condition)) in entries.into_iter().enumerate() {
+                events.push(event);
+                if let Some(condition) = condition {
+                    conditions.insert((frame, index), condition);
+                }
+            }
             value.insert(frame, events);
         }
 
-        Ok(value)
+        Ok((value, conditions))
     }
 }
 
@@ -212,14 +369,18 @@ struct AnimationEventsDeserializer<'a> {
     pub type_registry: &'a TypeRegistry,
 }
 
+/// Event of an animation clip with its condition, if any.
+type AnimationEventEntry = (Box<dyn PartialReflect>, Option<AnimationEventCondition>);
+
 impl<'de> DeserializeSeed<'de> for AnimationEventsDeserializer<'_> {
-    type Value = Vec<Box<dyn PartialReflect>>;
+    type Value = Vec<AnimationEventEntry>;
 
     fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
     where
         D: Deserializer<'de>,
     {
-        deserializer.deserialize_map(AnimationEventsVisitor {
+        /* Either a map of reflect types or a list of event entries */
+        deserializer.deserialize_any(AnimationEventsVisitor {
             type_registry: self.type_registry,
         })
     }
@@ -230,10 +391,10 @@ struct AnimationEventsVisitor<'a> {
 }
 
 impl<'de> Visitor<'de> for AnimationEventsVisitor<'_> {
-    type Value = Vec<Box<dyn PartialReflect>>;
+    type Value = Vec<AnimationEventEntry>;
 
     fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
-        formatter.write_str("map of reflect types")
+        formatter.write_str("map of reflect types or list of event entries")
     }
 
     fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
@@ -241,10 +402,10 @@ impl<'de> Visitor<'de> for AnimationEventsVisitor<'_> {
         A: serde::de::SeqAccess<'de>,
     {
         let mut dynamic_properties = Vec::new();
-        while let Some(entity) =
-            seq.next_element_seed(ReflectDeserializer::new(self.type_registry))?
-        {
-            dynamic_properties.push(entity);
+        while let Some(entry) = seq.next_element_seed(AnimationEventEntryDeserializer {
+            type_registry: self.type_registry,
+        })? {
+            dynamic_properties.push(entry);
         }
 
         Ok(dynamic_properties)
@@ -266,12 +427,86 @@ impl<'de> Visitor<'de> for AnimationEventsVisitor<'_> {
                 )));
             }
 
-            entries.push(map.next_value_seed(TypedReflectDeserializer::new(
+            entries.push((
+                map.next_value_seed(TypedReflectDeserializer::new(
+                    registration,
+                    self.type_registry,
+                ))?,
+                None,
+            ));
+        }
+
+        Ok(entries)
+    }
+}
+
+struct AnimationEventEntryDeserializer<'a> {
+    pub type_registry: &'a TypeRegistry,
+}
+
+impl<'de> DeserializeSeed<'de> for AnimationEventEntryDeserializer<'_> {
+    type Value = AnimationEventEntry;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        /* Essentially a map with a single reflect type */
+        /* optional condition of type AnimationEventCondition */
+        deserializer.deserialize_map(AnimationEventEntryVisitor {
+            type_registry: self.type_registry,
+        })
+    }
+}
+
+struct AnimationEventEntryVisitor<'a> {
+    pub type_registry: &'a TypeRegistry,
+}
+
+impl<'de> Visitor<'de> for AnimationEventEntryVisitor<'_> {
+    type Value = AnimationEventEntry;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        formatter.write_str("map of a reflect type and an optional condition")
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: serde::de::MapAccess<'de>,
+    {
+        let mut event = None;
+        let mut condition = None;
+        while let Some(key) = map.next_key::<String>()? {
+            if key == "condition" {
+                if condition.is_some() {
+                    return Err(Error::duplicate_field("condition"));
+                }
+                let value = map.next_value::<AnimationEventCondition>()?;
+                if let Some(marker) = &value.marker
+                    && self.type_registry.get_with_type_path(marker).is_none()
+                {
+                    return Err(Error::custom(format_args!(
+                        "marker component `{marker}` is not registered"
+                    )));
+                }
+                condition = Some(value);
+                continue;
+            }
+
+            if event.is_some() {
+                return Err(Error::custom("an event entry can only contain one event"));
+            }
+            let registration = self
+                .type_registry
+                .get_with_type_path(&key)
+                .ok_or_else(|| Error::custom(format_args!("no registration found for `{key}`")))?;
+            event = Some(map.next_value_seed(TypedReflectDeserializer::new(
                 registration,
                 self.type_registry,
             ))?);
         }
 
-        Ok(entries)
+        let event = event.ok_or_else(|| Error::custom("event entry contains no event"))?;
+        Ok((event, condition))
     }
 }
@@ -0,0 +1,345 @@
+//! This module defines the [`AnimationStateMachine2D`] asset and its loader.
+//!
+
+use bevy::{
+    asset::{AssetLoader, LoadContext, io::Reader},
+    platform::collections::HashMap,
+    prelude::{Asset, Handle},
+    reflect::{Reflect, TypePath},
+};
+use serde::Deserialize;
+use thiserror::Error;
+
+use super::AnimationClip2DSet;
+
+/// Value of a parameter of an [`AnimationStateMachine2D`].
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize, Reflect)]
+pub enum AnimationParameter {
+    /// A boolean parameter.
+    Bool(bool),
+    /// A floating point parameter.
+    Float(f32),
+    /// An integer parameter.
+    Int(i32),
+    /// A trigger, that is reset once a transition consumed it.
+    Trigger(bool),
+}
+
+/// Condition of an [`AnimationTransition2D`] on a named parameter.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub enum AnimationCondition {
+    /// The bool parameter is `true`.
+    True(String),
+    /// The bool parameter is `false`.
+    False(String),
+    /// The float or int parameter is greater than the value.
+    Greater(String, f32),
+    /// The float or int parameter is less than the value.
+    Less(String, f32),
+    /// The int parameter is equal to the value.
+    Equal(String, i32),
+    /// The int parameter is not equal to the value.
+    NotEqual(String, i32),
+    /// The trigger parameter is set.
+    Triggered(String),
+}
+
+impl AnimationCondition {
+    /// Name of the parameter this condition depends on.
+    pub fn parameter(&self) -> &str {
+        match self {
+            AnimationCondition::True(name)
+            | AnimationCondition::False(name)
+            | AnimationCondition::Greater(name, _)
+            | AnimationCondition::Less(name, _)
+            | AnimationCondition::Equal(name, _)
+            | AnimationCondition::NotEqual(name, _)
+            | AnimationCondition::Triggered(name) => name,
+        }
+    }
+
+    /// Check if the condition is met by the given parameter value.
+    ///
+    /// Returns `false` if the type of the parameter does not fit the condition.
+    pub fn is_met(&self, value: AnimationParameter) -> bool {
+        match (self, value) {
+            (AnimationCondition::True(_), AnimationParameter::Bool(value)) => value,
+            (AnimationCondition::False(_), AnimationParameter::Bool(value)) => !value,
+            (AnimationCondition::Greater(_, threshold), AnimationParameter::Float(value)) => {
+                value > *threshold
+            }
+            (AnimationCondition::Greater(_, threshold), AnimationParameter::Int(value)) => {
+                value as f32 > *threshold
+            }
+            (AnimationCondition::Less(_, threshold), AnimationParameter::Float(value)) => {
+                value < *threshold
+            }
+            (AnimationCondition::Less(_, threshold), AnimationParameter::Int(value)) => {
+                (value as f32) < *threshold
+            }
+            (AnimationCondition::Equal(_, expected), AnimationParameter::Int(value)) => {
+                value == *expected
+            }
+            (AnimationCondition::NotEqual(_, expected), AnimationParameter::Int(value)) => {
+                value != *expected
+            }
+            (AnimationCondition::Triggered(_), AnimationParameter::Trigger(value)) => value,
+            _ => false,
+        }
+    }
+
+    /// Check if a parameter of this type can be used for the condition.
+    fn accepts(&self, value: AnimationParameter) -> bool {
+        matches!(
+            (self, value),
+            (
+                AnimationCondition::True(_) | AnimationCondition::False(_),
+                AnimationParameter::Bool(_)
+            ) | (
+                AnimationCondition::Greater(..) | AnimationCondition::Less(..),
+                AnimationParameter::Float(_) | AnimationParameter::Int(_)
+            ) | (
+                AnimationCondition::Equal(..) | AnimationCondition::NotEqual(..),
+                AnimationParameter::Int(_)
+            ) | (
+                AnimationCondition::Triggered(_),
+                AnimationParameter::Trigger(_)
+            )
+        )
+    }
+}
+
+/// State of an [`AnimationStateMachine2D`], that plays an animation clip.
+#[derive(Debug, Clone, Deserialize)]
+pub struct AnimationState2D {
+    /// Name of the animation clip in the [`AnimationClip2DSet`] of the state machine.
+    pub clip: String,
+    /// How often the animation clip is played. Repeats forever if `None`.
+    #[serde(default)]
+    pub repeat: Option<u32>,
+    /// Speed of the animation playback.
+    #[serde(default = "default_speed")]
+    pub speed: f32,
+    /// Priority of the [`AnimationRequest`](crate::animation::AnimationRequest) to play the animation clip.
+    ///
+    /// While the lock of the playing animation holds, only states with a higher priority are transitioned to.
+    #[serde(default)]
+    pub priority: i32,
+}
+
+fn default_speed() -> f32 {
+    1.0
+}
+
+/// Transition of an [`AnimationStateMachine2D`] into another state.
+#[derive(Debug, Clone, Deserialize)]
+pub struct AnimationTransition2D {
+    /// Name of the state to transition to.
+    ///
+    /// A transition to the state it starts from can only happen once its animation clip has finished.
+    pub to: String,
+    /// All conditions that need to be met for the transition.
+    #[serde(default)]
+    pub conditions: Vec<AnimationCondition>,
+    /// Normalized time the current animation clip needs to have played, before the transition can happen.
+    /// E.g. `1.0` waits for the first cycle to complete.
+    #[serde(default)]
+    pub exit_time: Option<f32>,
+}
+
+/// Data driven state machine that decides which animation clip of an [`AnimationClip2DSet`] is played.
+#[derive(Asset, TypePath, Debug)]
+pub struct AnimationStateMachine2D {
+    /// The animation clips the states refer to.
+    clips: Handle<AnimationClip2DSet>,
+    /// Name of the state the state machine starts in.
+    initial_state: String,
+    /// Default values of all parameters.
+    parameters: HashMap<String, AnimationParameter>,
+    /// All states identified by their name.
+    states: HashMap<String, AnimationState2D>,
+    /// Transitions from a state identified by its name.
+    transitions: HashMap<String, Vec<AnimationTransition2D>>,
+    /// Transitions that can happen from any state.
+    any_state_transitions: Vec<AnimationTransition2D>,
+}
+
+/// Possible errors that can be produced by [`AnimationStateMachine2D`]
+#[non_exhaustive]
+#[derive(Debug, Error)]
+pub enum AnimationStateMachine2DError {
+    /// Error that occurs, if a state is referenced that does not exist.
+    #[error("State {0} does not exist")]
+    UnknownState(String),
+    /// Error that occurs, if a condition references a parameter that does not exist.
+    #[error("Parameter {0} does not exist")]
+    UnknownParameter(String),
+    /// Error that occurs, if a condition can not be used with the type of its parameter.
+    #[error("Condition {0:?} can not be used with parameter {1:?}")]
+    ParameterTypeMismatch(AnimationCondition, AnimationParameter),
+}
+
+impl AnimationStateMachine2D {
+    /// Creates a valid [`AnimationStateMachine2D`]
+    pub fn new(
+        clips: Handle<AnimationClip2DSet>,
+        initial_state: String,
+        parameters: HashMap<String, AnimationParameter>,
+        states: HashMap<String, AnimationState2D>,
+        transitions: HashMap<String, Vec<AnimationTransition2D>>,
+        any_state_transitions: Vec<AnimationTransition2D>,
+    ) -> Result<Self, AnimationStateMachine2DError> {
+        let check_state = |name: &String| {
+            if states.contains_key(name) {
+                Ok(())
+            } else {
+                Err(AnimationStateMachine2DError::UnknownState(name.clone()))
+            }
+        };
+
+        check_state(&initial_state)?;
+        for (from, from_transitions) in transitions.iter() {
+            check_state(from)?;
+            for transition in from_transitions.iter() {
+                check_state(&transition.to)?;
+            }
+        }
+        for transition in any_state_transitions.iter() {
+            check_state(&transition.to)?;
+        }
+
+        for condition in transitions
+            .values()
+            .flatten()
+            .chain(any_state_transitions.iter())
+            .flat_map(|transition| transition.conditions.iter())
+        {
+            let Some(parameter) = parameters.get(condition.parameter()) else {
+                return Err(AnimationStateMachine2DError::UnknownParameter(
+                    condition.parameter().to_string(),
+                ));
+            };
+            if !condition.accepts(*parameter) {
+                return Err(AnimationStateMachine2DError::ParameterTypeMismatch(
+                    condition.clone(),
+                    *parameter,
+                ));
+            }
+        }
+
+        Ok(Self {
+            clips,
+            initial_state,
+            parameters,
+            states,
+            transitions,
+            any_state_transitions,
+        })
+    }
+
+    /// The animation clips the states refer to.
+    #[inline]
+    pub fn clips(&self) -> &Handle<AnimationClip2DSet> {
+        &self.clips
+    }
+
+    /// Name of the state the state machine starts in.
+    #[inline]
+    pub fn initial_state(&self) -> &str {
+        &self.initial_state
+    }
+
+    /// Default values of all parameters.
+    #[inline]
+    pub fn parameters(&self) -> &HashMap<String, AnimationParameter> {
+        &self.parameters
+    }
+
+    /// All states identified by their name.
+    #[inline]
+    pub fn states(&self) -> &HashMap<String, AnimationState2D> {
+        &self.states
+    }
+
+    /// Transitions from the given state.
+    #[inline]
+    pub fn transitions(&self, state: &str) -> &[AnimationTransition2D] {
+        self.transitions
+            .get(state)
+            .map(Vec::as_slice)
+            .unwrap_or(&[])
+    }
+
+    /// Transitions that can happen from any state.
+    #[inline]
+    pub fn any_state_transitions(&self) -> &[AnimationTransition2D] {
+        &self.any_state_transitions
+    }
+}
+
+/// File representation of an [`AnimationStateMachine2D`].
+#[derive(Deserialize)]
+struct AnimationStateMachine2DFile {
+    clips: String,
+    initial_state: String,
+    #[serde(default)]
+    parameters: HashMap<String, AnimationParameter>,
+    states: HashMap<String, AnimationState2D>,
+    #[serde(default)]
+    transitions: HashMap<String, Vec<AnimationTransition2D>>,
+    #[serde(default)]
+    any_state_transitions: Vec<AnimationTransition2D>,
+}
+
+#[derive(Debug, Default)]
+pub(crate) struct AnimationStateMachine2DLoader;
+
+/// Possible errors that can be produced by AnimationStateMachine2DLoader.
+#[non_exhaustive]
+#[derive(Debug, Error)]
+pub enum AnimationStateMachine2DLoaderError {
+    /// An [IOError](std::io::Error).
+    #[error("Could not open file: {0}")]
+    Io(#[from] std::io::Error),
+    /// A [SpannedError](ron::error::SpannedError).
+    #[error("Could not parse RON: {0}")]
+    RonSpannedError(#[from] ron::error::SpannedError),
+    /// An [`AnimationStateMachine2DError`].
+    #[error("AnimationStateMachine2D has internal error: {0}")]
+    AnimationStateMachine2DError(#[from] AnimationStateMachine2DError),
+}
+
+/// File extension for animation state machine files written in ron.
+const FILE_EXTENSIONS: &[&str] = &["fsm.ron"];
+
+impl AssetLoader for AnimationStateMachine2DLoader {
+    type Asset = AnimationStateMachine2D;
+    type Settings = ();
+    type Error = AnimationStateMachine2DLoaderError;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &(),
+        load_context: &mut LoadContext<'_>,
+    ) -> Result<Self::Asset, Self::Error> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+
+        let file = ron::de::from_bytes::<AnimationStateMachine2DFile>(&bytes)?;
+        let clips = load_context.load(file.clips);
+
+        Ok(AnimationStateMachine2D::new(
+            clips,
+            file.initial_state,
+            file.parameters,
+            file.states,
+            file.transitions,
+            file.any_state_transitions,
+        )?)
+    }
+
+    fn extensions(&self) -> &[&str] {
+        FILE_EXTENSIONS
+    }
+}
@@ -0,0 +1,109 @@
+//! This module defines time curves, that remap the playback of an [`AnimationClip2D`](super::AnimationClip2D).
+//!
+
+use serde::Deserialize;
+
+use super::AnimationClip2DError;
+
+/// Curve that maps the normalized playback time of an animation clip to the normalized time its frames are displayed at.
+///
+/// The playback time still advances linearly, so the completion of an animation is not affected.
+/// E.g. [`TimeCurve::EaseInOut`] slows down the start and end of the clip, while the middle of the clip is played faster.
+#[derive(Debug, Default, Clone, PartialEq, Deserialize)]
+pub enum TimeCurve {
+    /// The frames are displayed at their keyframe timestamps.
+    #[default]
+    Linear,
+    /// Starts slow and speeds up towards the end (cubic).
+    EaseIn,
+    /// Starts fast and slows down towards the end (cubic).
+    EaseOut,
+    /// Starts and ends slow (cubic).
+    EaseInOut,
+    /// Piecewise linear curve through the given points of normalized playback time and normalized clip time.
+    ///
+    /// The curve needs to start at (0.0, 0.0) and end at (1.0, 1.0).
+    /// The playback times need to be strictly increasing and the clip times must not decrease.
+    /// Equal clip times hold the frame for that part of the playback.
+    /// A curve with less than two points is sampled like [`TimeCurve::Linear`].
+    Piecewise(Vec<(f32, f32)>),
+}
+
+impl TimeCurve {
+    /// Normalized clip time at the given normalized playback time, which is clamped to the range [0.0, 1.0].
+    pub fn sample(&self, t: f32) -> f32 {
+        let t = t.clamp(0.0, 1.0);
+        match self {
+            TimeCurve::Linear => t,
+            TimeCurve::EaseIn => t * t * t,
+            TimeCurve::EaseOut => 1.0 - (1.0 - t).powi(3),
+            TimeCurve::EaseInOut => {
+                if t < 0.5 {
+                    4.0 * t * t * t
+                } else {
+                    1.0 - (2.0 - 2.0 * t).powi(3) / 2.0
+                }
+            }
+            // Curves with less than two points are not valid, so they are played linearly
+            TimeCurve::Piecewise(points) if points.len() < 2 => t,
+            TimeCurve::Piecewise(points) => {
+                let i = points
+                    .partition_point(|(x, _)| *x <= t)
+                    .clamp(1, points.len() - 1);
+                let ((x0, y0), (x1, y1)) = (points[i - 1], points[i]);
+                y0 + (t - x0) / (x1 - x0) * (y1 - y0)
+            }
+        }
+    }
+
+    /// Earliest normalized playback time at which the given normalized clip time is reached.
+    pub fn inverse(&self, y: f32) -> f32 {
+        let y = y.clamp(0.0, 1.0);
+        match self {
+            TimeCurve::Linear => y,
+            TimeCurve::EaseIn => y.cbrt(),
+            TimeCurve::EaseOut => 1.0 - (1.0 - y).cbrt(),
+            TimeCurve::EaseInOut => {
+                if y < 0.5 {
+                    (y / 4.0).cbrt()
+                } else {
+                    1.0 - (2.0 * (1.0 - y)).cbrt() / 2.0
+                }
+            }
+            TimeCurve::Piecewise(points) if points.len() < 2 => y,
+            TimeCurve::Piecewise(points) => {
+                let i = points
+                    .partition_point(|(_, py)| *py < y)
+                    .clamp(1, points.len() - 1);
+                let ((x0, y0), (x1, y1)) = (points[i - 1], points[i]);
+                if y1 > y0 {
+                    x0 + (y - y0) / (y1 - y0) * (x1 - x0)
+                } else {
+                    x0
+                }
+            }
+        }
+    }
+
+    /// Checks that the points of a [`TimeCurve::Piecewise`] describe a valid curve.
+    pub(crate) fn validate(&self) -> Result<(), AnimationClip2DError> {
+        let TimeCurve::Piecewise(points) = self else {
+            return Ok(());
+        };
+
+        if points.first() != Some(&(0.0, 0.0)) || points.last() != Some(&(1.0, 1.0)) {
+            return Err(AnimationClip2DError::InvalidTimeCurve(
+                "it needs to start at (0.0, 0.0) and end at (1.0, 1.0)",
+            ));
+        }
+        if points
+            .windows(2)
+            .any(|pair| !(pair[0].0 < pair[1].0 && pair[0].1 <= pair[1].1))
+        {
+            return Err(AnimationClip2DError::InvalidTimeCurve(
+                "its playback times need to be strictly increasing and its clip times must not decrease",
+            ));
+        }
+        Ok(())
+    }
+}
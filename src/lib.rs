@@ -6,6 +6,7 @@ use bevy::prelude::{App, Plugin};
 
 pub mod animation;
 pub mod asset;
+mod rng;
 
 /// Adds support for 2d animation loading and playing.
 pub struct Animation2DPlugin;
@@ -22,8 +23,13 @@ impl Plugin for Animation2DPlugin {
 /// `use bevy_trickfilm::prelude::*;` to import common components and plugins.
 pub mod prelude {
     pub use crate::Animation2DPlugin;
+    pub use crate::animation::{
+        AnimationClip2DSetHandle, AnimationController2D, AnimationPlayer2D, AnimationPlayer2DPlugin,
+    };
     pub use crate::animation::{AnimationEvent, AnimationEventAppExtension};
-    pub use crate::animation::{AnimationPlayer2D, AnimationPlayer2DPlugin};
-    pub use crate::asset::{Animation2DLoaderPlugin, AnimationClip2D, AnimationClip2DSet};
+    pub use crate::asset::{
+        Animation2DLoaderPlugin, AnimationClip2D, AnimationClip2DSet, AnimationClips,
+        AnimationStateMachine2D,
+    };
     pub use bevy_trickfilm_derive::*;
 }
@@ -0,0 +1,71 @@
+mod common;
+
+use bevy::prelude::*;
+use bevy_trickfilm::{asset::MissingAnimationClips, prelude::*};
+use common::*;
+
+#[derive(AnimationClips, Clone, Copy)]
+#[trickfilm(path = "gabe-idle-run-animation.trickfilm.ron")]
+enum Gabe {
+    Idle,
+    Run,
+    #[clip("run")]
+    Sprint,
+}
+
+#[derive(AnimationClips)]
+#[trickfilm(path = "gabe-idle-run-animation.trickfilm.ron")]
+enum MissingGabe {
+    Idle,
+    JumpHigh,
+}
+
+fn load_set(app: &mut App) -> Handle<AnimationClip2DSet> {
+    let set = Gabe::load_set(app.world().resource::<AssetServer>());
+    update_until(app, |app| {
+        app.world()
+            .resource::<Assets<AnimationClip2DSet>>()
+            .contains(&set)
+    });
+    set
+}
+
+#[test]
+fn names() {
+    assert_eq!(Gabe::PATH, "gabe-idle-run-animation.trickfilm.ron");
+    assert_eq!(Gabe::NAMES, &["idle", "run", "run"]);
+    assert_eq!(Gabe::Idle.name(), "idle");
+    assert_eq!(Gabe::Sprint.name(), "run");
+    assert_eq!(MissingGabe::Idle.name(), "idle");
+    assert_eq!(MissingGabe::JumpHigh.name(), "jump_high");
+}
+
+#[test]
+fn validate() {
+    let mut app = app(50);
+    let set = load_set(&mut app);
+    let sets = app.world().resource::<Assets<AnimationClip2DSet>>();
+    let set = sets.get(&set).unwrap();
+
+    assert!(Gabe::validate(set).is_ok());
+    assert!(Gabe::Run.handle(set).is_some());
+    let MissingAnimationClips { path, names } = MissingGabe::validate(set).unwrap_err();
+    assert_eq!(path, MissingGabe::PATH);
+    assert_eq!(names, vec!["jump_high"]);
+}
+
+#[test]
+fn start_clip() {
+    let mut app = app(50);
+    let set = load_set(&mut app);
+    let run = Gabe::Run.load(app.world().resource::<AssetServer>());
+    let mut player = AnimationPlayer2D::default();
+    player.start_clip(Gabe::Sprint);
+    let entity = app
+        .world_mut()
+        .spawn((player, AnimationClip2DSetHandle(set)))
+        .id();
+
+    update_until(&mut app, |app| get_player(app, entity).clip_playing(&run));
+    assert_eq!(get_player(&app, entity).animation_name(), Some("run"));
+}
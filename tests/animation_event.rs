@@ -0,0 +1,103 @@
+mod common;
+
+use bevy::{platform::collections::HashMap, prelude::*, reflect::PartialReflect};
+use bevy_trickfilm::{animation::event::EventTarget, asset::Keyframes, prelude::*};
+use common::*;
+
+#[derive(Debug, Clone, Event, Reflect, AnimationEvent)]
+struct Named {
+    msg: String,
+    #[animationevent(target)]
+    target: EventTarget,
+}
+
+#[derive(Debug, Clone, Event, Reflect, AnimationEvent)]
+struct Tuple(u32, #[animationevent(target)] EventTarget);
+
+#[derive(Debug, Clone, Event, Reflect, AnimationEvent)]
+enum Hit {
+    Strike {
+        damage: u32,
+        #[animationevent(target)]
+        target: EventTarget,
+    },
+    Block(#[animationevent(target)] EventTarget),
+    Miss,
+}
+
+#[derive(Debug, Clone, Event, Reflect, AnimationEvent)]
+struct Untargeted(u32);
+
+#[test]
+fn set_target() {
+    let entity = Entity::from_raw(7);
+
+    let mut named = Named {
+        msg: "step".into(),
+        target: EventTarget::default(),
+    };
+    named.set_target(EventTarget(entity));
+    assert_eq!(*named.target, entity);
+    assert_eq!(named.msg, "step");
+
+    let mut tuple = Tuple(3, EventTarget::default());
+    tuple.set_target(EventTarget(entity));
+    assert_eq!(*tuple.1, entity);
+    assert_eq!(tuple.0, 3);
+
+    let mut strike = Hit::Strike {
+        damage: 5,
+        target: EventTarget::default(),
+    };
+    strike.set_target(EventTarget(entity));
+    assert!(matches!(strike, Hit::Strike { damage: 5, target } if *target == entity));
+
+    let mut block = Hit::Block(EventTarget::default());
+    block.set_target(EventTarget(entity));
+    assert!(matches!(block, Hit::Block(target) if *target == entity));
+
+    let mut miss = Hit::Miss;
+    miss.set_target(EventTarget(entity));
+    assert!(matches!(miss, Hit::Miss));
+
+    let mut untargeted = Untargeted(1);
+    untargeted.set_target(EventTarget(entity));
+    assert_eq!(untargeted.0, 1);
+}
+
+#[test]
+fn events_are_sent_to_the_player() {
+    let mut app = app(50);
+    app.add_animation_event::<Tuple>()
+        .add_animation_event::<Hit>();
+
+    let events: HashMap<usize, Vec<Box<dyn PartialReflect>>> = HashMap::from_iter([
+        (
+            0,
+            vec![Box::new(Tuple(1, EventTarget::default())) as Box<dyn PartialReflect>],
+        ),
+        (
+            1,
+            vec![Box::new(Hit::Strike {
+                damage: 2,
+                target: EventTarget::default(),
+            }) as Box<dyn PartialReflect>],
+        ),
+    ]);
+    let animation_clip =
+        AnimationClip2D::new(None, Keyframes::KeyframesRange(0..4), 0.4, Some(events)).unwrap();
+    let animation_clip = add_clip(&mut app, animation_clip);
+    let mut player = AnimationPlayer2D::default();
+    player.start(animation_clip);
+    let entity = spawn(&mut app, player);
+
+    let mut tuples = Vec::new();
+    let mut hits = Vec::new();
+    for _ in 0..4 {
+        app.update();
+        tuples.extend(drain_events::<Tuple>(&mut app));
+        hits.extend(drain_events::<Hit>(&mut app));
+    }
+    assert!(matches!(tuples[..], [Tuple(1, target)] if *target == entity));
+    assert!(matches!(hits[..], [Hit::Strike { damage: 2, target }] if *target == entity));
+}
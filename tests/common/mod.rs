@@ -0,0 +1,79 @@
+#![allow(dead_code)]
+
+use std::time::Duration;
+
+use bevy::{asset::AssetPlugin, prelude::*, time::TimeUpdateStrategy};
+use bevy_trickfilm::{asset::Keyframes, prelude::*};
+
+/// App with the animation plugins, advancing by the given milliseconds each update.
+pub fn app(step_millis: u64) -> App {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, AssetPlugin::default(), Animation2DPlugin));
+    app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+        step_millis,
+    )));
+    app
+}
+
+/// Animation clip with four frames, evenly spread over the duration.
+pub fn clip(duration: f32) -> AnimationClip2D {
+    AnimationClip2D::new(None, Keyframes::KeyframesRange(0..4), duration, None).unwrap()
+}
+
+pub fn add_clip(app: &mut App, clip: AnimationClip2D) -> Handle<AnimationClip2D> {
+    app.world_mut()
+        .resource_mut::<Assets<AnimationClip2D>>()
+        .add(clip)
+}
+
+pub fn spawn(app: &mut App, player: AnimationPlayer2D) -> Entity {
+    app.world_mut()
+        .spawn((
+            player,
+            Sprite {
+                texture_atlas: Some(TextureAtlas::default()),
+                ..Default::default()
+            },
+        ))
+        .id()
+}
+
+pub fn get_player(app: &App, entity: Entity) -> &AnimationPlayer2D {
+    app.world().get::<AnimationPlayer2D>(entity).unwrap()
+}
+
+pub fn get_player_mut(app: &mut App, entity: Entity) -> Mut<'_, AnimationPlayer2D> {
+    app.world_mut()
+        .get_mut::<AnimationPlayer2D>(entity)
+        .unwrap()
+}
+
+pub fn atlas_index(app: &App, entity: Entity) -> usize {
+    app.world()
+        .get::<Sprite>(entity)
+        .unwrap()
+        .texture_atlas
+        .as_ref()
+        .unwrap()
+        .index
+}
+
+/// Take all events of the given type, that were sent since they were taken the last time.
+pub fn drain_events<E: Event>(app: &mut App) -> Vec<E> {
+    app.world_mut()
+        .resource_mut::<Events<E>>()
+        .drain()
+        .collect()
+}
+
+/// Update the app until the condition is met, while assets are loading in the background.
+pub fn update_until(app: &mut App, mut condition: impl FnMut(&mut App) -> bool) {
+    for _ in 0..500 {
+        app.update();
+        if condition(app) {
+            return;
+        }
+        std::thread::sleep(Duration::from_millis(2));
+    }
+    panic!("condition was not met in time");
+}
@@ -0,0 +1,84 @@
+mod common;
+
+use bevy::{platform::collections::HashMap, prelude::*, reflect::PartialReflect};
+use bevy_trickfilm::{
+    animation::{AnimationDrive, event::EventTarget},
+    asset::Keyframes,
+    prelude::*,
+};
+use common::*;
+
+#[derive(Debug, Clone, Event, Reflect, AnimationEvent)]
+struct Reached(usize, #[animationevent(target)] EventTarget);
+
+/// Player of an animation clip with an event on each frame, driven by the given value.
+fn driven(drive: AnimationDrive) -> (App, Entity) {
+    let mut app = app(50);
+    app.add_animation_event::<Reached>();
+    let events = (0..4)
+        .map(|frame| {
+            (
+                frame,
+                vec![Box::new(Reached(frame, EventTarget::default())) as Box<dyn PartialReflect>],
+            )
+        })
+        .collect::<HashMap<_, _>>();
+    let animation_clip = add_clip(
+        &mut app,
+        AnimationClip2D::new(None, Keyframes::KeyframesRange(0..4), 0.4, Some(events)).unwrap(),
+    );
+    let mut player = AnimationPlayer2D::default();
+    player.start(animation_clip).set_drive(drive);
+    let entity = spawn(&mut app, player);
+    app.update();
+    (app, entity)
+}
+
+/// Drive the animation to the given value and return the frames whose events were sent.
+fn drive_to(app: &mut App, entity: Entity, drive: AnimationDrive) -> Vec<usize> {
+    get_player_mut(app, entity).set_drive(drive);
+    app.update();
+    drain_events::<Reached>(app)
+        .into_iter()
+        .map(|Reached(frame, _)| frame)
+        .collect()
+}
+
+#[test]
+fn normalized_drive_ignores_time() {
+    let (mut app, entity) = driven(AnimationDrive::Normalized(0.5));
+    assert_eq!(atlas_index(&app, entity), 2);
+
+    for _ in 0..10 {
+        app.update();
+    }
+    assert_eq!(atlas_index(&app, entity), 2);
+    assert!((get_player(&app, entity).seek_time() - 0.2).abs() < 1e-4);
+
+    // The value is clamped to the animation clip
+    drive_to(&mut app, entity, AnimationDrive::Normalized(2.0));
+    assert_eq!(atlas_index(&app, entity), 3);
+    drive_to(&mut app, entity, AnimationDrive::Normalized(-1.0));
+    assert_eq!(atlas_index(&app, entity), 0);
+}
+
+#[test]
+fn seconds_drive() {
+    let (mut app, entity) = driven(AnimationDrive::Seconds(0.15));
+    assert_eq!(atlas_index(&app, entity), 1);
+
+    drive_to(&mut app, entity, AnimationDrive::Seconds(0.35));
+    assert_eq!(atlas_index(&app, entity), 3);
+}
+
+#[test]
+fn crossed_frames_send_events_in_both_directions() {
+    let (mut app, entity) = driven(AnimationDrive::Normalized(0.0));
+    drain_events::<Reached>(&mut app);
+
+    let forward = drive_to(&mut app, entity, AnimationDrive::Normalized(1.0));
+    assert_eq!(forward, vec![1, 2, 3]);
+    let backward = drive_to(&mut app, entity, AnimationDrive::Normalized(0.0));
+    assert_eq!(backward, vec![2, 1, 0]);
+    assert!(drive_to(&mut app, entity, AnimationDrive::Normalized(0.0)).is_empty());
+}
@@ -0,0 +1,275 @@
+mod common;
+
+use std::time::Duration;
+
+use bevy::{
+    asset::AssetPlugin, platform::collections::HashMap, prelude::*, reflect::PartialReflect,
+    time::TimeUpdateStrategy,
+};
+use bevy_trickfilm::{
+    animation::event::EventTarget,
+    asset::{AnimationEventCondition, Keyframes},
+    prelude::*,
+};
+use common::*;
+
+#[derive(Debug, Clone, Event, Reflect, AnimationEvent)]
+struct Step {
+    n: u32,
+    #[reflect(skip_serializing)]
+    #[animationevent(target)]
+    target: EventTarget,
+}
+
+#[derive(Component, Reflect)]
+struct Armored;
+
+fn step(n: u32) -> Box<dyn PartialReflect> {
+    Box::new(Step {
+        n,
+        target: EventTarget::default(),
+    })
+}
+
+/// Animation clip with the given events on its first frame and their conditions identified by their index.
+fn conditional_clip(
+    events: Vec<Box<dyn PartialReflect>>,
+    conditions: impl IntoIterator<Item = (usize, AnimationEventCondition)>,
+) -> AnimationClip2D {
+    AnimationClip2D::new(
+        None,
+        Keyframes::KeyframesRange(0..4),
+        0.4,
+        Some(HashMap::from_iter([(0, events)])),
+    )
+    .unwrap()
+    .with_event_conditions(
+        conditions
+            .into_iter()
+            .map(|(index, condition)| ((0, index), condition))
+            .collect(),
+    )
+    .unwrap()
+}
+
+fn steps_app() -> App {
+    let mut app = app(50);
+    app.add_animation_event::<Step>().register_type::<Armored>();
+    app
+}
+
+fn collect_steps(app: &mut App, updates: usize) -> Vec<(Entity, u32)> {
+    let mut steps = Vec::new();
+    for _ in 0..updates {
+        app.update();
+        steps.extend(
+            drain_events::<Step>(app)
+                .into_iter()
+                .map(|Step { n, target }| (*target, n)),
+        );
+    }
+    steps
+}
+
+#[test]
+fn conditions_of_events_of_the_same_type() {
+    let mut app = steps_app();
+    let animation_clip = add_clip(
+        &mut app,
+        conditional_clip(
+            vec![step(1), step(2)],
+            [(
+                0,
+                AnimationEventCondition {
+                    on_loop: Some(1),
+                    ..Default::default()
+                },
+            )],
+        ),
+    );
+    let mut player = AnimationPlayer2D::default();
+    player.start(animation_clip).repeat();
+    let entity = spawn(&mut app, player);
+
+    // Three cycles of 8 updates each
+    let steps = collect_steps(&mut app, 24);
+    assert_eq!(steps, [(entity, 2), (entity, 1), (entity, 2), (entity, 2)]);
+}
+
+#[test]
+fn marker_condition() {
+    let mut app = steps_app();
+    let animation_clip = add_clip(
+        &mut app,
+        conditional_clip(
+            vec![step(1)],
+            [(
+                0,
+                AnimationEventCondition {
+                    marker: Some(Armored::type_path().to_string()),
+                    ..Default::default()
+                },
+            )],
+        ),
+    );
+    let mut player = AnimationPlayer2D::default();
+    player.start(animation_clip);
+    let armored = spawn(&mut app, player.clone());
+    app.world_mut().entity_mut(armored).insert(Armored);
+    spawn(&mut app, player);
+
+    assert_eq!(collect_steps(&mut app, 2), [(armored, 1)]);
+}
+
+#[test]
+fn probability_is_rolled_per_player() {
+    let mut app = steps_app();
+    let animation_clip = add_clip(
+        &mut app,
+        conditional_clip(
+            vec![step(1)],
+            [(
+                0,
+                AnimationEventCondition {
+                    probability: 0.5,
+                    ..Default::default()
+                },
+            )],
+        ),
+    );
+    let mut player = AnimationPlayer2D::default();
+    player.start(animation_clip).repeat();
+    let first = spawn(&mut app, player.clone());
+    player.set_event_seed(5);
+    let second = spawn(&mut app, player.clone());
+    let third = spawn(&mut app, player);
+    get_player_mut(&mut app, first).set_event_seed(5);
+
+    // 40 cycles
+    let steps = collect_steps(&mut app, 320);
+    let cycles = |entity| steps.iter().filter(|(target, _)| *target == entity).count();
+    // Players with the same seed roll the same numbers, no matter how many other players roll
+    assert_eq!(cycles(first), cycles(second));
+    assert_eq!(cycles(second), cycles(third));
+    assert!((5..35).contains(&cycles(first)));
+}
+
+#[test]
+fn snapshot_restores_event_rolls() {
+    let mut app = steps_app();
+    let animation_clip = add_clip(
+        &mut app,
+        conditional_clip(
+            vec![step(1)],
+            [(
+                0,
+                AnimationEventCondition {
+                    probability: 0.5,
+                    ..Default::default()
+                },
+            )],
+        ),
+    );
+    let mut player = AnimationPlayer2D::default();
+    player.start(animation_clip).repeat().set_event_seed(5);
+    let original = spawn(&mut app, player.clone());
+    player.set_event_seed(99);
+    let restored = spawn(&mut app, player);
+
+    collect_steps(&mut app, 20);
+    let snapshot = get_player(&app, original).snapshot();
+    get_player_mut(&mut app, restored).restore(&snapshot);
+
+    let steps = collect_steps(&mut app, 160);
+    let rolls = |entity| steps.iter().filter(|(target, _)| *target == entity).count();
+    assert!(rolls(original) > 0);
+    assert_eq!(rolls(original), rolls(restored));
+}
+
+#[test]
+fn conditions_are_loaded_per_event() {
+    let directory = std::env::temp_dir().join("bevy_trickfilm_event_conditions");
+    std::fs::create_dir_all(&directory).unwrap();
+    std::fs::write(
+        directory.join("conditions.trickfilm.ron"),
+        r#"{
+            "walk": (
+                keyframes: KeyframesRange((start: 0, end: 4)),
+                duration: 0.4,
+                events: {
+                    0: [
+                        { "event_conditions::Step": (n: 1) },
+                        {
+                            "event_conditions::Step": (n: 2),
+                            "condition": (probability: 0.5, forward_only: true),
+                        },
+                    ],
+                },
+            ),
+        }"#,
+    )
+    .unwrap();
+
+    let mut app = App::new();
+    app.add_plugins((
+        MinimalPlugins,
+        AssetPlugin {
+            file_path: directory.to_string_lossy().into_owned(),
+            ..Default::default()
+        },
+        Animation2DPlugin,
+    ))
+    .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+        50,
+    )))
+    .add_animation_event::<Step>();
+    let animation_clip: Handle<AnimationClip2D> = app
+        .world()
+        .resource::<AssetServer>()
+        .load("conditions.trickfilm.ron#walk");
+    update_until(&mut app, |app| {
+        app.world()
+            .resource::<Assets<AnimationClip2D>>()
+            .contains(&animation_clip)
+    });
+
+    let clips = app.world().resource::<Assets<AnimationClip2D>>();
+    let clip = clips.get(&animation_clip).unwrap();
+    assert_eq!(clip.events()[&0].len(), 2);
+    assert!(clip.event_condition(0, 0).is_none());
+    assert_eq!(
+        clip.event_condition(0, 1),
+        Some(&AnimationEventCondition {
+            probability: 0.5,
+            forward_only: true,
+            ..Default::default()
+        })
+    );
+}
+
+#[test]
+fn reload_without_conditions_sends_suppressed_events() {
+    let mut app = steps_app();
+    let animation_clip = add_clip(
+        &mut app,
+        conditional_clip(
+            vec![step(1)],
+            [(
+                0,
+                AnimationEventCondition {
+                    probability: 0.0,
+                    ..Default::default()
+                },
+            )],
+        ),
+    );
+    let mut player = AnimationPlayer2D::default();
+    player.start(animation_clip.clone()).repeat();
+    let entity = spawn(&mut app, player);
+    assert!(collect_steps(&mut app, 1).is_empty());
+
+    app.world_mut()
+        .resource_mut::<Assets<AnimationClip2D>>()
+        .insert(&animation_clip, conditional_clip(vec![step(1)], []));
+    assert_eq!(collect_steps(&mut app, 9), [(entity, 1)]);
+}
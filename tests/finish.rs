@@ -0,0 +1,86 @@
+mod common;
+
+use bevy::prelude::*;
+use bevy_trickfilm::{animation::FinishBehaviour, prelude::*};
+use common::*;
+
+fn finish(finish_behaviour: FinishBehaviour) -> (App, Entity) {
+    let mut app = app(50);
+    let animation_clip = add_clip(&mut app, clip(0.2));
+    let mut player = AnimationPlayer2D::default();
+    player
+        .start(animation_clip)
+        .set_finish_behaviour(finish_behaviour);
+    let entity = spawn(&mut app, player);
+    update_until(&mut app, |app| {
+        app.world()
+            .get::<AnimationPlayer2D>(entity)
+            .is_none_or(AnimationPlayer2D::finished)
+    });
+    (app, entity)
+}
+
+#[test]
+fn reset_shows_first_frame() {
+    let (app, entity) = finish(FinishBehaviour::Reset);
+    assert_eq!(atlas_index(&app, entity), 0);
+    assert_eq!(get_player(&app, entity).frame(), 0);
+}
+
+#[test]
+fn hold_shows_last_frame() {
+    let (app, entity) = finish(FinishBehaviour::Hold);
+    assert_eq!(atlas_index(&app, entity), 3);
+}
+
+#[test]
+fn hide_once() {
+    let (mut app, entity) = finish(FinishBehaviour::Hide);
+    assert_eq!(
+        app.world().get::<Visibility>(entity),
+        Some(&Visibility::Hidden)
+    );
+
+    // Showing the entity again is not undone while the player stays finished without being updated
+    app.world_mut()
+        .entity_mut(entity)
+        .remove::<Sprite>()
+        .insert(Visibility::Visible);
+    for _ in 0..3 {
+        app.update();
+        assert_eq!(
+            app.world().get::<Visibility>(entity),
+            Some(&Visibility::Visible)
+        );
+    }
+}
+
+#[test]
+fn remove_player() {
+    let (app, entity) = finish(FinishBehaviour::RemovePlayer);
+    assert!(app.world().get::<AnimationPlayer2D>(entity).is_none());
+}
+
+#[test]
+fn despawn() {
+    let (app, entity) = finish(FinishBehaviour::Despawn);
+    assert!(app.world().get_entity(entity).is_err());
+}
+
+#[test]
+fn restored_finished_player_is_not_finished_again() {
+    let (mut app, entity) = finish(FinishBehaviour::Hide);
+    let snapshot = get_player(&app, entity).snapshot();
+    app.world_mut()
+        .entity_mut(entity)
+        .insert(Visibility::Visible);
+
+    get_player_mut(&mut app, entity).restore(&snapshot);
+    for _ in 0..3 {
+        app.update();
+        assert_eq!(
+            app.world().get::<Visibility>(entity),
+            Some(&Visibility::Visible)
+        );
+    }
+}
@@ -0,0 +1,83 @@
+mod common;
+
+use bevy::prelude::*;
+use bevy_trickfilm::{
+    animation::{AnimationGroup2D, AnimationGroups},
+    prelude::*,
+};
+use common::*;
+
+fn spawn_playing(app: &mut App, animation_clip: &Handle<AnimationClip2D>) -> Entity {
+    let mut player = AnimationPlayer2D::default();
+    player.start(animation_clip.clone()).repeat();
+    spawn(app, player)
+}
+
+fn groups(app: &mut App) -> Mut<'_, AnimationGroups> {
+    app.world_mut().resource_mut::<AnimationGroups>()
+}
+
+#[test]
+fn pause_and_resume_group() {
+    let mut app = app(50);
+    let animation_clip = add_clip(&mut app, clip(0.4));
+    let member = spawn_playing(&mut app, &animation_clip);
+    let paused_member = spawn_playing(&mut app, &animation_clip);
+    let other = spawn_playing(&mut app, &animation_clip);
+    for entity in [member, paused_member] {
+        app.world_mut()
+            .entity_mut(entity)
+            .insert(AnimationGroup2D::new("enemies"));
+    }
+    get_player_mut(&mut app, paused_member).pause();
+    groups(&mut app).pause("enemies");
+    for _ in 0..3 {
+        app.update();
+    }
+    assert_eq!(get_player(&app, member).seek_time(), 0.0);
+    assert!(get_player(&app, other).seek_time() > 0.0);
+
+    // Players paused on their own stay paused
+    groups(&mut app).resume("enemies");
+    app.update();
+    assert!(get_player(&app, member).seek_time() > 0.0);
+    assert_eq!(get_player(&app, paused_member).seek_time(), 0.0);
+}
+
+#[test]
+fn group_speed_is_combined() {
+    let mut app = app(50);
+    let animation_clip = add_clip(&mut app, clip(0.4));
+    let entity = spawn_playing(&mut app, &animation_clip);
+    get_player_mut(&mut app, entity).set_speed(0.5);
+    app.world_mut()
+        .entity_mut(entity)
+        .insert(AnimationGroup2D::new("slow_motion"));
+    groups(&mut app).set_speed("slow_motion", 4.0);
+    app.update();
+    app.update();
+
+    assert!((get_player(&app, entity).seek_time() - 0.1).abs() < 1e-4);
+    assert_eq!(get_player(&app, entity).speed(), 0.5);
+}
+
+#[test]
+fn group_propagates_to_children() {
+    let mut app = app(50);
+    let animation_clip = add_clip(&mut app, clip(0.4));
+    let propagated = spawn_playing(&mut app, &animation_clip);
+    let not_propagated = spawn_playing(&mut app, &animation_clip);
+    app.world_mut()
+        .spawn(AnimationGroup2D::new("ui").with_propagate(true))
+        .add_child(propagated);
+    app.world_mut()
+        .spawn(AnimationGroup2D::new("ui"))
+        .add_child(not_propagated);
+    groups(&mut app).pause("ui");
+    for _ in 0..3 {
+        app.update();
+    }
+
+    assert_eq!(get_player(&app, propagated).seek_time(), 0.0);
+    assert!(get_player(&app, not_propagated).seek_time() > 0.0);
+}
@@ -0,0 +1,109 @@
+mod common;
+
+use bevy::{platform::collections::HashMap, prelude::*};
+use bevy_trickfilm::{animation::*, asset::AnimationClip2DSetChanged, prelude::*};
+use common::*;
+
+/// Replace the animation clip while a paused player is halfway through its third frame.
+fn reload(mode: AnimationReloadMode, duration: f32) -> (App, Entity) {
+    let mut app = app(50);
+    app.insert_resource(mode);
+    let animation_clip = add_clip(&mut app, clip(1.0));
+    let mut player = AnimationPlayer2D::default();
+    player.start(animation_clip.clone()).seek_to(0.6).pause();
+    let entity = spawn(&mut app, player);
+    app.update();
+    app.update();
+    assert_eq!(get_player(&app, entity).frame(), 2);
+
+    app.world_mut()
+        .resource_mut::<Assets<AnimationClip2D>>()
+        .insert(&animation_clip, clip(duration));
+    app.update();
+    (app, entity)
+}
+
+#[test]
+fn reload_keeps_proportional_position() {
+    let (app, entity) = reload(AnimationReloadMode::Proportional, 2.0);
+    let player = get_player(&app, entity);
+    assert!((player.seek_time() - 1.2).abs() < 1e-5);
+    assert_eq!(player.frame(), 2);
+}
+
+#[test]
+fn reload_keeps_frame() {
+    let (app, entity) = reload(AnimationReloadMode::Frame, 2.0);
+    let player = get_player(&app, entity);
+    assert!((player.seek_time() - 1.0).abs() < 1e-5);
+    assert_eq!(player.frame(), 2);
+}
+
+#[test]
+fn reload_shorter_clip_keeps_proportional_position() {
+    let (mut app, entity) = reload(AnimationReloadMode::Proportional, 0.5);
+    assert!((get_player(&app, entity).seek_time() - 0.3).abs() < 1e-5);
+    app.update();
+    assert!((get_player(&app, entity).seek_time() - 0.3).abs() < 1e-5);
+}
+
+/// Animation clip set with the given labels, all referring to the same animation clip.
+fn clip_set(animation_clip: &Handle<AnimationClip2D>, labels: &[&str]) -> AnimationClip2DSet {
+    AnimationClip2DSet {
+        animations: labels
+            .iter()
+            .map(|label| (label.to_string(), animation_clip.clone()))
+            .collect(),
+        variants: HashMap::new(),
+    }
+}
+
+#[test]
+fn reload_reports_changed_labels() {
+    let mut app = app(50);
+    let animation_clip = add_clip(&mut app, clip(0.4));
+    let set = app
+        .world_mut()
+        .resource_mut::<Assets<AnimationClip2DSet>>()
+        .add(clip_set(&animation_clip, &["idle", "run"]));
+    app.update();
+    app.update();
+    // Adding an animation clip set is no change
+    assert!(drain_events::<AnimationClip2DSetChanged>(&mut app).is_empty());
+
+    app.world_mut()
+        .resource_mut::<Assets<AnimationClip2DSet>>()
+        .insert(&set, clip_set(&animation_clip, &["walk", "idle", "jump"]));
+    app.update();
+    app.update();
+    let changed = drain_events::<AnimationClip2DSetChanged>(&mut app);
+    assert_eq!(changed.len(), 1);
+    assert_eq!(changed[0].id, set.id());
+    assert_eq!(changed[0].added, ["jump", "walk"]);
+    assert_eq!(changed[0].removed, ["run"]);
+
+    // Modifying an animation clip set without changing its labels is no change either
+    app.world_mut()
+        .resource_mut::<Assets<AnimationClip2DSet>>()
+        .insert(&set, clip_set(&animation_clip, &["jump", "idle", "walk"]));
+    app.update();
+    app.update();
+    assert!(drain_events::<AnimationClip2DSetChanged>(&mut app).is_empty());
+}
+
+#[test]
+fn loading_reports_no_changed_labels() {
+    let mut app = app(50);
+    let set: Handle<AnimationClip2DSet> = app
+        .world()
+        .resource::<AssetServer>()
+        .load("gabe-idle-run-animation.trickfilm.ron");
+    update_until(&mut app, |app| {
+        app.world()
+            .resource::<AssetServer>()
+            .is_loaded_with_dependencies(&set)
+    });
+    app.update();
+    app.update();
+    assert!(drain_events::<AnimationClip2DSetChanged>(&mut app).is_empty());
+}
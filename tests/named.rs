@@ -0,0 +1,84 @@
+mod common;
+
+use bevy::prelude::*;
+use bevy_trickfilm::{
+    animation::{AnimationClip2DNotFound, AnimationClip2DSetHandle},
+    prelude::*,
+};
+use common::*;
+
+const PATH: &str = "gabe-idle-run-animation.trickfilm.ron";
+
+fn spawn_named(app: &mut App, name: &str) -> Entity {
+    let set = app.world().resource::<AssetServer>().load(PATH);
+    let mut player = AnimationPlayer2D::default();
+    player.start_named(name).repeat();
+    let entity = spawn(app, player);
+    app.world_mut()
+        .entity_mut(entity)
+        .insert(AnimationClip2DSetHandle(set));
+    entity
+}
+
+fn load_clip(app: &App, name: &str) -> Handle<AnimationClip2D> {
+    app.world()
+        .resource::<AssetServer>()
+        .load(format!("{PATH}#{name}"))
+}
+
+#[test]
+fn play_named_switches_once() {
+    let mut app = app(50);
+    let entity = spawn_named(&mut app, "idle");
+    let idle = load_clip(&app, "idle");
+    let run = load_clip(&app, "run");
+    update_until(&mut app, |app| get_player(app, entity).clip_playing(&idle));
+
+    get_player_mut(&mut app, entity).play_named("run").repeat();
+    update_until(&mut app, |app| get_player(app, entity).clip_playing(&run));
+    for _ in 0..3 {
+        app.update();
+    }
+    let seek_time = get_player(&app, entity).seek_time();
+    assert!(seek_time > 0.0);
+
+    // Playing the same name again does not restart the animation
+    get_player_mut(&mut app, entity).play_named("run");
+    assert_eq!(get_player(&app, entity).seek_time(), seek_time);
+    assert_eq!(get_player(&app, entity).animation_name(), Some("run"));
+}
+
+#[test]
+fn unknown_name_is_reported() {
+    let mut app = app(50);
+    let entity = spawn_named(&mut app, "jump");
+    let mut not_found = Vec::new();
+    update_until(&mut app, |app| {
+        not_found.extend(drain_events::<AnimationClip2DNotFound>(app));
+        !get_player(app, entity).pending()
+    });
+
+    assert!(get_player(&app, entity).failed());
+    assert_eq!(not_found.len(), 1);
+    assert_eq!(not_found[0].entity, entity);
+    assert_eq!(not_found[0].name, "jump");
+}
+
+#[test]
+fn missing_animation_clip_set_is_reported() {
+    let mut app = app(50);
+    let mut player = AnimationPlayer2D::default();
+    player.start_named("run");
+    let entity = spawn(&mut app, player);
+    app.update();
+
+    assert!(get_player(&app, entity).failed());
+    let not_found = drain_events::<AnimationClip2DNotFound>(&mut app);
+    assert_eq!(not_found.len(), 1);
+    assert_eq!(not_found[0].entity, entity);
+    assert_eq!(not_found[0].name, "run");
+
+    // The player is reported only once
+    app.update();
+    assert!(drain_events::<AnimationClip2DNotFound>(&mut app).is_empty());
+}
@@ -0,0 +1,104 @@
+mod common;
+
+use bevy::prelude::*;
+use bevy_trickfilm::{animation::*, prelude::*};
+use common::*;
+
+fn load_failed(app: &mut App, path: &'static str) {
+    let animation_clip = app.world().resource::<AssetServer>().load(path);
+    let mut player = AnimationPlayer2D::default();
+    player.start(animation_clip);
+    let entity = spawn(app, player);
+
+    update_until(app, |app| !get_player(app, entity).pending());
+    assert!(get_player(app, entity).failed());
+    let failed = drain_events::<AnimationClip2DLoadFailed>(app);
+    assert_eq!(failed.len(), 1);
+    assert_eq!(failed[0].entity, entity);
+}
+
+#[test]
+fn missing_file_fails() {
+    let mut app = app(50);
+    load_failed(&mut app, "missing.trickfilm.ron#idle");
+}
+
+#[test]
+fn missing_label_fails() {
+    let mut app = app(50);
+    load_failed(&mut app, "gabe-idle-run-animation.trickfilm.ron#nope");
+}
+
+#[test]
+fn existing_label_becomes_ready() {
+    let mut app = app(50);
+    let animation_clip: Handle<AnimationClip2D> = app
+        .world()
+        .resource::<AssetServer>()
+        .load("gabe-idle-run-animation.trickfilm.ron#run");
+    let mut player = AnimationPlayer2D::default();
+    player.start(animation_clip.clone());
+    let entity = spawn(&mut app, player);
+
+    update_until(&mut app, |app| !get_player(app, entity).pending());
+    let ready = drain_events::<AnimationClip2DReady>(&mut app);
+    assert_eq!(ready.len(), 1);
+    assert_eq!(ready[0].entity, entity);
+    assert_eq!(ready[0].animation_clip, animation_clip.id());
+    assert!(drain_events::<AnimationClip2DLoadFailed>(&mut app).is_empty());
+    assert!(!get_player(&app, entity).failed());
+}
+
+/// Player of an animation clip, that is not added to the assets until [`finish_loading`] is called.
+fn loading(app: &mut App, pending_policy: PendingPolicy) -> (Entity, Handle<AnimationClip2D>) {
+    let animation_clip = app
+        .world()
+        .resource::<Assets<AnimationClip2D>>()
+        .reserve_handle();
+    let mut player = AnimationPlayer2D::default();
+    player
+        .start(animation_clip.clone())
+        .repeat()
+        .set_pending_policy(pending_policy);
+    let entity = spawn(app, player);
+    for _ in 0..5 {
+        app.update();
+    }
+    (entity, animation_clip)
+}
+
+fn finish_loading(app: &mut App, animation_clip: &Handle<AnimationClip2D>) {
+    app.world_mut()
+        .resource_mut::<Assets<AnimationClip2D>>()
+        .insert(animation_clip, clip(0.4));
+    app.update();
+}
+
+#[test]
+fn start_when_ready() {
+    let mut app = app(50);
+    let (entity, animation_clip) = loading(&mut app, PendingPolicy::StartWhenReady);
+    assert!(get_player(&app, entity).pending());
+    assert_eq!(get_player(&app, entity).seek_time(), 0.0);
+    assert!(drain_events::<AnimationClip2DReady>(&mut app).is_empty());
+
+    finish_loading(&mut app, &animation_clip);
+    assert!(!get_player(&app, entity).pending());
+    assert_eq!(drain_events::<AnimationClip2DReady>(&mut app).len(), 1);
+    assert!(get_player(&app, entity).seek_time() <= 0.05 + f32::EPSILON);
+    assert_eq!(atlas_index(&app, entity), 0);
+}
+
+#[test]
+fn accumulate_while_loading() {
+    let mut app = app(50);
+    let (entity, animation_clip) = loading(&mut app, PendingPolicy::Accumulate);
+    assert!(get_player(&app, entity).pending());
+    assert!((get_player(&app, entity).seek_time() - 0.2).abs() < 1e-4);
+
+    finish_loading(&mut app, &animation_clip);
+    assert!(!get_player(&app, entity).pending());
+    // Playback continues as if the animation clip had been loaded from the start
+    assert!(get_player(&app, entity).seek_time() >= 0.2);
+    assert_eq!(atlas_index(&app, entity), 2);
+}
@@ -0,0 +1,116 @@
+mod common;
+
+use bevy::{
+    animation::RepeatAnimation, platform::collections::HashMap, prelude::*, reflect::PartialReflect,
+};
+use bevy_trickfilm::{animation::event::EventTarget, asset::Keyframes, prelude::*};
+use common::*;
+
+#[derive(Debug, Clone, Event, Reflect, AnimationEvent)]
+struct Reached(usize, #[animationevent(target)] EventTarget);
+
+/// State of a ping-pong animation after an update.
+#[derive(Debug)]
+struct PingPongUpdate {
+    frame: usize,
+    just_finished_cycle: bool,
+    reversed: bool,
+    reached: Vec<usize>,
+}
+
+/// Updates of a ping-pong animation with an event on each frame, until it finished.
+fn ping_pong_updates(repeat: RepeatAnimation) -> (App, Entity, Vec<PingPongUpdate>) {
+    let mut app = app(50);
+    app.add_animation_event::<Reached>();
+    let events = (0..4)
+        .map(|frame| {
+            (
+                frame,
+                vec![Box::new(Reached(frame, EventTarget::default())) as Box<dyn PartialReflect>],
+            )
+        })
+        .collect::<HashMap<_, _>>();
+    let animation_clip = add_clip(
+        &mut app,
+        AnimationClip2D::new(None, Keyframes::KeyframesRange(0..4), 0.4, Some(events)).unwrap(),
+    );
+    let mut player = AnimationPlayer2D::default();
+    player
+        .start(animation_clip)
+        .set_repeat_mode(repeat)
+        .set_ping_pong(true);
+    let entity = spawn(&mut app, player);
+
+    let mut updates = Vec::new();
+    update_until(&mut app, |app| {
+        let reached = drain_events::<Reached>(app)
+            .into_iter()
+            .map(|Reached(frame, _)| frame)
+            .collect();
+        let player = get_player(app, entity);
+        updates.push(PingPongUpdate {
+            frame: atlas_index(app, entity),
+            just_finished_cycle: player.just_finished_cycle(),
+            reversed: player.reversed(),
+            reached,
+        });
+        get_player(app, entity).finished()
+    });
+    (app, entity, updates)
+}
+
+/// Indices of the updates that finished a cycle.
+fn cycle_updates(updates: &[PingPongUpdate]) -> Vec<usize> {
+    updates
+        .iter()
+        .enumerate()
+        .filter(|(_, update)| update.just_finished_cycle)
+        .map(|(i, _)| i)
+        .collect()
+}
+
+/// Frames whose events were sent, in the order they were sent.
+fn reached(updates: &[PingPongUpdate]) -> Vec<usize> {
+    updates
+        .iter()
+        .flat_map(|update| update.reached.iter().copied())
+        .collect()
+}
+
+#[test]
+fn bounces_at_the_end() {
+    let (app, entity, updates) = ping_pong_updates(RepeatAnimation::Count(2));
+    let frames: Vec<usize> = updates.iter().map(|update| update.frame).collect();
+    let turn = frames.iter().position(|frame| *frame == 3).unwrap();
+
+    // Forward up to the last frame, then backward down to the first frame
+    assert!(frames[..=turn].is_sorted());
+    assert!(frames[turn..].iter().rev().is_sorted());
+    assert_eq!(frames.last(), Some(&0));
+    assert_eq!(get_player(&app, entity).completions(), 2);
+    assert!(get_player(&app, entity).speed() < 0.0);
+
+    // The bounce finishes the first cycle and reverses the animation, the last update finishes the second one
+    let cycles = cycle_updates(&updates);
+    assert_eq!(cycles.len(), 2);
+    let bounce = cycles[0];
+    assert_eq!(cycles[1], updates.len() - 1);
+    assert!(updates[..bounce].iter().all(|update| !update.reversed));
+    assert!(updates[bounce..].iter().all(|update| update.reversed));
+
+    // Each crossed frame sends its event once, the turning frame is not sent again
+    assert_eq!(reached(&updates), [0, 1, 2, 3, 2, 1, 0]);
+}
+
+#[test]
+fn each_pass_is_a_cycle() {
+    let (_, _, updates) = ping_pong_updates(RepeatAnimation::Never);
+    let frames: Vec<usize> = updates.iter().map(|update| update.frame).collect();
+
+    // A single cycle only plays forward
+    assert!(frames.is_sorted());
+    assert_eq!(frames.last(), Some(&3));
+    assert_eq!(cycle_updates(&updates), [updates.len() - 1]);
+    assert!(updates.iter().all(|update| !update.reversed));
+    assert_eq!(reached(&updates), [0, 1, 2, 3]);
+}
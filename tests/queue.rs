@@ -0,0 +1,64 @@
+mod common;
+
+use bevy::prelude::*;
+use bevy_trickfilm::{
+    animation::{AnimationClip2DReady, QueuedAnimationStarted},
+    prelude::*,
+};
+use common::*;
+
+#[test]
+fn queue_plays_in_order() {
+    let mut app = app(30);
+    let first = add_clip(&mut app, clip(0.1));
+    let second = add_clip(&mut app, clip(0.2));
+    let third = add_clip(&mut app, clip(0.4));
+    let mut player = AnimationPlayer2D::default();
+    player
+        .start(first)
+        .enqueue(second.clone())
+        .enqueue(third.clone());
+    let entity = spawn(&mut app, player);
+    assert_eq!(get_player(&app, entity).queue().len(), 2);
+
+    let mut started = Vec::new();
+    let mut ready = 0;
+    update_until(&mut app, |app| {
+        started.extend(drain_events::<QueuedAnimationStarted>(app));
+        ready += drain_events::<AnimationClip2DReady>(app).len();
+        get_player(app, entity).clip_playing(&second)
+    });
+    // The time left over from the finished animation is carried over to the next animation clip
+    assert!((get_player(&app, entity).seek_time() - 0.02).abs() < 1e-4);
+
+    update_until(&mut app, |app| {
+        started.extend(drain_events::<QueuedAnimationStarted>(app));
+        ready += drain_events::<AnimationClip2DReady>(app).len();
+        assert!(!get_player(app, entity).pending());
+        get_player(app, entity).finished()
+    });
+    assert!(get_player(&app, entity).clip_playing(&third));
+    // Queued animation clips, that are loaded already, are ready without another event
+    assert_eq!(ready, 1);
+    let started: Vec<_> = started
+        .iter()
+        .map(|started| (started.animation_clip, started.remaining))
+        .collect();
+    assert_eq!(started, vec![(second.id(), 1), (third.id(), 0)]);
+}
+
+#[test]
+fn start_clears_queue() {
+    let mut app = app(30);
+    let first = add_clip(&mut app, clip(0.1));
+    let second = add_clip(&mut app, clip(0.2));
+    let mut player = AnimationPlayer2D::default();
+    player.start(first.clone()).enqueue(second.clone());
+    let entity = spawn(&mut app, player);
+    app.update();
+
+    get_player_mut(&mut app, entity).start(first);
+    assert_eq!(get_player(&app, entity).queue().len(), 0);
+    update_until(&mut app, |app| get_player(app, entity).finished());
+    assert!(!get_player(&app, entity).clip_playing(&second));
+}
@@ -0,0 +1,112 @@
+mod common;
+
+use bevy::prelude::*;
+use bevy_trickfilm::{
+    animation::{AnimationLock, AnimationRequest},
+    prelude::*,
+};
+use common::*;
+
+/// Player of an animation clip requested with priority 5 and the given lock.
+fn locked(lock: AnimationLock) -> (App, Entity, Handle<AnimationClip2D>) {
+    let mut app = app(50);
+    let attack = add_clip(&mut app, clip(0.4));
+    let entity = spawn(&mut app, AnimationPlayer2D::default());
+    get_player_mut(&mut app, entity).request(
+        AnimationRequest::new(attack.clone())
+            .with_priority(5)
+            .with_lock(lock),
+    );
+    app.update();
+    (app, entity, attack)
+}
+
+#[test]
+fn highest_priority_wins() {
+    let mut app = app(50);
+    let low = add_clip(&mut app, clip(0.4));
+    let high = add_clip(&mut app, clip(0.4));
+    let entity = spawn(&mut app, AnimationPlayer2D::default());
+    get_player_mut(&mut app, entity)
+        .request(AnimationRequest::new(high.clone()).with_priority(5))
+        .request(AnimationRequest::new(low).with_priority(1));
+    app.update();
+
+    assert!(get_player(&app, entity).clip_playing(&high));
+    assert_eq!(get_player(&app, entity).priority(), 5);
+    assert!(get_player(&app, entity).requests().is_empty());
+}
+
+#[test]
+fn lock_holds_until_finished() {
+    let (mut app, entity, attack) = locked(AnimationLock::UntilFinished);
+    let idle = add_clip(&mut app, clip(0.4));
+
+    assert!(get_player(&app, entity).locked());
+    let mut updates = 0;
+    while get_player(&app, entity).clip_playing(&attack) {
+        get_player_mut(&mut app, entity).request(AnimationRequest::new(idle.clone()));
+        app.update();
+        updates += 1;
+        assert!(updates < 20, "the lock did not release");
+    }
+    // Requests of the same priority are ignored until the animation finished
+    assert!(updates >= 8);
+    assert!(get_player(&app, entity).clip_playing(&idle));
+    assert_eq!(get_player(&app, entity).priority(), 0);
+}
+
+#[test]
+fn higher_priority_interrupts_lock() {
+    let (mut app, entity, _) = locked(AnimationLock::UntilFinished);
+    let hurt = add_clip(&mut app, clip(0.4));
+    get_player_mut(&mut app, entity).request(AnimationRequest::new(hurt.clone()).with_priority(6));
+    app.update();
+
+    assert!(get_player(&app, entity).clip_playing(&hurt));
+}
+
+#[test]
+fn lock_holds_until_frame() {
+    let (mut app, entity, attack) = locked(AnimationLock::UntilFrame(2));
+    let idle = add_clip(&mut app, clip(0.4));
+
+    let mut frame = 0;
+    while get_player(&app, entity).clip_playing(&attack) {
+        frame = atlas_index(&app, entity);
+        get_player_mut(&mut app, entity).request(AnimationRequest::new(idle.clone()));
+        app.update();
+    }
+    // The request is accepted once the frame was reached
+    assert_eq!(frame, 2);
+    assert!(get_player(&app, entity).clip_playing(&idle));
+}
+
+#[test]
+fn play_bypasses_lock() {
+    let (mut app, entity, attack) = locked(AnimationLock::UntilFinished);
+    let hurt = add_clip(&mut app, clip(0.4));
+    get_player_mut(&mut app, entity).play(hurt.clone());
+    app.update();
+
+    assert!(get_player(&app, entity).clip_playing(&hurt));
+    assert!(!get_player(&app, entity).clip_playing(&attack));
+    assert_eq!(get_player(&app, entity).priority(), 0);
+    assert!(!get_player(&app, entity).locked());
+}
+
+#[test]
+fn play_unlocked_releases_lock() {
+    let (mut app, entity, attack) = locked(AnimationLock::UntilFinished);
+    let idle = add_clip(&mut app, clip(0.4));
+    app.update();
+    let seek_time = get_player(&app, entity).seek_time();
+
+    // The animation clip keeps playing, but can be interrupted by any request
+    get_player_mut(&mut app, entity).play_unlocked(attack.clone());
+    assert_eq!(get_player(&app, entity).seek_time(), seek_time);
+    assert!(!get_player(&app, entity).locked());
+    get_player_mut(&mut app, entity).request(AnimationRequest::new(idle.clone()));
+    app.update();
+    assert!(get_player(&app, entity).clip_playing(&idle));
+}
@@ -0,0 +1,85 @@
+mod common;
+
+use bevy::prelude::*;
+use bevy_trickfilm::{
+    animation::{AnimationClip2DReady, RestingAnimation2D},
+    prelude::*,
+};
+use common::*;
+
+/// Player of a one-shot animation clip, whose entity has the given resting animation.
+fn one_shot(
+    app: &mut App,
+    resting: impl FnOnce(Handle<AnimationClip2D>) -> RestingAnimation2D,
+) -> (Entity, Handle<AnimationClip2D>) {
+    let attack = add_clip(app, clip(0.1));
+    let idle = add_clip(app, clip(0.4));
+    let mut player = AnimationPlayer2D::default();
+    player.start(attack);
+    let entity = spawn(app, player);
+    app.world_mut()
+        .entity_mut(entity)
+        .insert(resting(idle.clone()));
+    (entity, idle)
+}
+
+#[test]
+fn resting_starts_right_away() {
+    let mut app = app(30);
+    let idle = add_clip(&mut app, clip(0.4));
+    let entity = spawn(&mut app, AnimationPlayer2D::default());
+    app.world_mut()
+        .entity_mut(entity)
+        .insert(RestingAnimation2D::new(idle.clone()));
+    app.update();
+
+    assert!(get_player(&app, entity).clip_playing(&idle));
+}
+
+#[test]
+fn returns_with_leftover() {
+    let mut app = app(30);
+    let (entity, idle) = one_shot(&mut app, RestingAnimation2D::new);
+    let mut ready = 0;
+    update_until(&mut app, |app| {
+        ready += drain_events::<AnimationClip2DReady>(app).len();
+        get_player(app, entity).clip_playing(&idle)
+    });
+
+    assert!((get_player(&app, entity).seek_time() - 0.02).abs() < 1e-4);
+    assert!(!get_player(&app, entity).finished());
+    for _ in 0..5 {
+        assert!(!get_player(&app, entity).pending());
+        app.update();
+        ready += drain_events::<AnimationClip2DReady>(&mut app).len();
+    }
+    assert_eq!(ready, 1);
+}
+
+#[test]
+fn returns_without_leftover() {
+    let mut app = app(30);
+    let (entity, idle) = one_shot(&mut app, |idle| {
+        RestingAnimation2D::new(idle).with_carry_leftover(false)
+    });
+    update_until(&mut app, |app| get_player(app, entity).clip_playing(&idle));
+
+    assert_eq!(get_player(&app, entity).seek_time(), 0.0);
+    assert_eq!(atlas_index(&app, entity), 0);
+}
+
+#[test]
+fn removed_resting_is_not_returned_to() {
+    let mut app = app(30);
+    let (entity, idle) = one_shot(&mut app, RestingAnimation2D::new);
+    app.update();
+    app.world_mut()
+        .entity_mut(entity)
+        .remove::<RestingAnimation2D>();
+    update_until(&mut app, |app| get_player(app, entity).finished());
+
+    for _ in 0..5 {
+        app.update();
+    }
+    assert!(!get_player(&app, entity).clip_playing(&idle));
+}
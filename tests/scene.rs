@@ -0,0 +1,60 @@
+mod common;
+
+use bevy::{ecs::entity::EntityHashMap, prelude::*, scene::serde::SceneDeserializer};
+use bevy_trickfilm::prelude::*;
+use common::*;
+use serde::de::DeserializeSeed;
+
+/// Save the animation player of the entity to a RON scene and load it again as a new entity.
+fn round_trip(app: &mut App, entity: Entity) -> Entity {
+    let world = app.world();
+    let registry = world.resource::<AppTypeRegistry>().clone();
+    let scene = DynamicSceneBuilder::from_world(world)
+        .deny_all_components()
+        .allow_component::<AnimationPlayer2D>()
+        .extract_entity(entity)
+        .build();
+    let serialized = scene.serialize(&registry.read()).unwrap();
+
+    let mut deserializer = ron::de::Deserializer::from_str(&serialized).unwrap();
+    let scene = SceneDeserializer {
+        type_registry: &registry.read(),
+    }
+    .deserialize(&mut deserializer)
+    .unwrap();
+    let mut entity_map = EntityHashMap::default();
+    scene
+        .write_to_world(app.world_mut(), &mut entity_map)
+        .unwrap();
+    entity_map[&entity]
+}
+
+#[test]
+fn player_round_trips_through_a_scene() {
+    let mut app = app(50);
+    let animation_clip: Handle<AnimationClip2D> = app
+        .world()
+        .resource::<AssetServer>()
+        .load("gabe-idle-run-animation.trickfilm.ron#run");
+    let mut player = AnimationPlayer2D::default();
+    player.start(animation_clip.clone()).repeat();
+    let entity = spawn(&mut app, player);
+    update_until(&mut app, |app| get_player(app, entity).duration().is_some());
+    for _ in 0..3 {
+        app.update();
+    }
+    get_player_mut(&mut app, entity).pause();
+    app.update();
+
+    let loaded = round_trip(&mut app, entity);
+    update_until(&mut app, |app| {
+        get_player(app, loaded).clip_playing(&animation_clip)
+    });
+
+    let original = get_player(&app, entity);
+    let loaded = get_player(&app, loaded);
+    assert!(loaded.clip_playing(&animation_clip));
+    assert!(loaded.paused());
+    assert_eq!(loaded.seek_time(), original.seek_time());
+    assert_eq!(loaded.frame(), original.frame());
+}
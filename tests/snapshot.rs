@@ -0,0 +1,206 @@
+mod common;
+
+use bevy::{platform::collections::HashMap, prelude::*, reflect::PartialReflect};
+use bevy_trickfilm::{
+    animation::{
+        AnimationClip2DReady, AnimationClip2DSetHandle, AnimationDrive, AnimationVariation2D,
+        FinishBehaviour, PendingPolicy, RestingAnimation2D, SeekEvents, event::EventTarget,
+    },
+    asset::Keyframes,
+    prelude::*,
+};
+use common::*;
+
+#[derive(Debug, Clone, Event, Reflect, AnimationEvent)]
+struct Reached {
+    frame: usize,
+    #[animationevent(target)]
+    target: EventTarget,
+}
+
+fn spawn_named(app: &mut App, name: &str) -> Entity {
+    let set = app
+        .world()
+        .resource::<AssetServer>()
+        .load("gabe-idle-run-animation.trickfilm.ron");
+    let mut player = AnimationPlayer2D::default();
+    player.start_named(name).repeat();
+    let entity = spawn(app, player);
+    app.world_mut()
+        .entity_mut(entity)
+        .insert(AnimationClip2DSetHandle(set));
+    entity
+}
+
+#[test]
+fn restore_then_snapshot_is_identity() {
+    let mut app = app(50);
+    let animation_clip = add_clip(&mut app, clip(0.4));
+    let resting = add_clip(&mut app, clip(0.2));
+    let mut player = AnimationPlayer2D::default();
+    player
+        .start(animation_clip)
+        .set_speed(1.5)
+        .set_pending_policy(PendingPolicy::default())
+        .set_finish_behaviour(FinishBehaviour::Reset)
+        .set_seek_events(SeekEvents::Fire)
+        .set_drive(AnimationDrive::Time)
+        .set_event_seed(3)
+        .set_variant_seed(4);
+    let entity = spawn(&mut app, player);
+    app.world_mut()
+        .entity_mut(entity)
+        .insert(RestingAnimation2D::new(resting));
+    for _ in 0..3 {
+        app.update();
+    }
+
+    let snapshot = get_player(&app, entity).snapshot();
+    // The frame just changed, so the last frame differs from it
+    assert_ne!(snapshot.last_frame(), snapshot.frame());
+    assert!(snapshot.resting().is_some());
+
+    let other = spawn(&mut app, AnimationPlayer2D::default());
+    get_player_mut(&mut app, other).restore(&snapshot);
+    assert_eq!(get_player(&app, other).snapshot(), snapshot);
+}
+
+#[test]
+fn restoring_another_clip_keeps_the_name() {
+    let mut app = app(50);
+    let run = spawn_named(&mut app, "run");
+    let idle = spawn_named(&mut app, "idle");
+    update_until(&mut app, |app| {
+        get_player(app, run).duration().is_some() && get_player(app, idle).duration().is_some()
+    });
+
+    let snapshot = get_player(&app, run).snapshot();
+    assert_eq!(snapshot.animation_name(), Some("run"));
+    get_player_mut(&mut app, idle).restore(&snapshot);
+    assert_eq!(get_player(&app, idle).animation_name(), Some("run"));
+    update_until(&mut app, |app| get_player(app, idle).duration().is_some());
+
+    assert_eq!(get_player(&app, idle).animation_name(), Some("run"));
+    assert_eq!(
+        get_player(&app, idle).animation_clip(),
+        get_player(&app, run).animation_clip()
+    );
+}
+
+#[test]
+fn restoring_a_resolving_name_does_not_get_stuck() {
+    let mut app = app(50);
+    // The animation clip set is not loaded yet, so the name is still resolving
+    let resolving = spawn_named(&mut app, "run");
+    let snapshot = get_player(&app, resolving).snapshot();
+
+    let restored = spawn_named(&mut app, "idle");
+    update_until(&mut app, |app| {
+        get_player(app, restored).duration().is_some()
+    });
+    get_player_mut(&mut app, restored).restore(&snapshot);
+    update_until(&mut app, |app| {
+        get_player(app, restored).duration().is_some()
+    });
+
+    assert_eq!(get_player(&app, restored).animation_name(), Some("run"));
+    assert_eq!(get_player(&app, restored).duration(), Some(0.6));
+}
+
+/// Frames whose events were sent since they were taken the last time.
+fn reached(app: &mut App) -> Vec<usize> {
+    drain_events::<Reached>(app)
+        .into_iter()
+        .map(|reached| reached.frame)
+        .collect()
+}
+
+#[test]
+fn rollback_does_not_send_events_again() {
+    let mut app = app(50);
+    app.add_animation_event::<Reached>();
+    let events = (0..4)
+        .map(|frame| {
+            (
+                frame,
+                vec![Box::new(Reached {
+                    frame,
+                    target: EventTarget::default(),
+                }) as Box<dyn PartialReflect>],
+            )
+        })
+        .collect::<HashMap<_, _>>();
+    let animation_clip = add_clip(
+        &mut app,
+        AnimationClip2D::new(None, Keyframes::KeyframesRange(0..4), 0.4, Some(events)).unwrap(),
+    );
+    let mut player = AnimationPlayer2D::default();
+    player.start(animation_clip).repeat();
+    let entity = spawn(&mut app, player);
+    for _ in 0..3 {
+        app.update();
+    }
+    assert_eq!(reached(&mut app), vec![0, 1]);
+    assert_eq!(drain_events::<AnimationClip2DReady>(&mut app).len(), 1);
+
+    // Predict two updates, then roll back to the frame the events were sent on
+    let snapshot = get_player(&app, entity).snapshot();
+    app.update();
+    app.update();
+    assert_eq!(reached(&mut app), vec![2]);
+    get_player_mut(&mut app, entity).restore(&snapshot);
+
+    app.update();
+    assert!(reached(&mut app).is_empty());
+    app.update();
+    assert_eq!(reached(&mut app), vec![2]);
+    assert!(drain_events::<AnimationClip2DReady>(&mut app).is_empty());
+}
+
+#[test]
+fn rollback_rewinds_variation() {
+    let mut app = app(50);
+    let animation_clip = add_clip(&mut app, clip(0.4));
+    let mut player = AnimationPlayer2D::default();
+    player.start(animation_clip.clone()).repeat();
+    let entity = spawn(&mut app, player);
+    app.world_mut()
+        .entity_mut(entity)
+        .insert(AnimationVariation2D::new().with_speed_range(0.5, 1.5));
+    app.update();
+
+    // Restart the animation after the snapshot, so it varies again
+    let restart = |app: &mut App| {
+        get_player_mut(app, entity)
+            .start(animation_clip.clone())
+            .repeat();
+        app.update();
+        let player = get_player(app, entity);
+        (player.seek_time(), player.speed())
+    };
+    let snapshot = get_player(&app, entity).snapshot();
+    let predicted = restart(&mut app);
+    assert_ne!(get_player(&app, entity).snapshot(), snapshot);
+
+    get_player_mut(&mut app, entity).restore(&snapshot);
+    assert_eq!(get_player(&app, entity).snapshot(), snapshot);
+    assert_eq!(restart(&mut app), predicted);
+}
+
+#[test]
+fn restore_keeps_cycles_finished_on_the_update() {
+    let mut app = app(50);
+    let animation_clip = add_clip(&mut app, clip(0.1));
+    let mut player = AnimationPlayer2D::default();
+    player.start(animation_clip).repeat();
+    let entity = spawn(&mut app, player);
+    update_until(&mut app, |app| {
+        get_player(app, entity).just_finished_cycle()
+    });
+
+    let snapshot = get_player(&app, entity).snapshot();
+    let other = spawn(&mut app, AnimationPlayer2D::default());
+    get_player_mut(&mut app, other).restore(&snapshot);
+    assert!(get_player(&app, other).just_finished_cycle());
+    assert_eq!(get_player(&app, other).snapshot(), snapshot);
+}
@@ -0,0 +1,250 @@
+mod common;
+
+use bevy::{platform::collections::HashMap, prelude::*};
+use bevy_trickfilm::{
+    animation::{AnimationController2D, AnimationLock, AnimationRequest},
+    asset::{
+        AnimationCondition, AnimationParameter, AnimationState2D, AnimationStateMachine2D,
+        AnimationTransition2D,
+    },
+    prelude::*,
+};
+use common::*;
+
+fn controller(app: &mut App, entity: Entity) -> Mut<'_, AnimationController2D> {
+    app.world_mut()
+        .get_mut::<AnimationController2D>(entity)
+        .unwrap()
+}
+
+#[test]
+fn transition_on_parameter() {
+    let mut app = app(50);
+    let state_machine = app
+        .world()
+        .resource::<AssetServer>()
+        .load("gabe-idle-run-animation.fsm.ron");
+    let entity = app
+        .world_mut()
+        .spawn((
+            AnimationPlayer2D::default(),
+            AnimationController2D::new(state_machine),
+        ))
+        .id();
+    let state = |app: &App| {
+        app.world()
+            .get::<AnimationController2D>(entity)
+            .unwrap()
+            .state()
+            .map(str::to_string)
+    };
+
+    update_until(&mut app, |app| state(app).as_deref() == Some("idle"));
+    app.world_mut()
+        .get_mut::<AnimationController2D>(entity)
+        .unwrap()
+        .set_float("speed", 1.0);
+    app.update();
+    assert_eq!(state(&app).as_deref(), Some("run"));
+}
+
+#[test]
+fn self_transition_waits_for_finish() {
+    let mut app = app(50);
+    let attack = add_clip(&mut app, clip(0.2));
+    let clips = app
+        .world_mut()
+        .resource_mut::<Assets<AnimationClip2DSet>>()
+        .add(AnimationClip2DSet {
+            animations: HashMap::from_iter([("attack".to_string(), attack.clone())]),
+            variants: HashMap::new(),
+        });
+    let state_machine = AnimationStateMachine2D::new(
+        clips,
+        "attack".to_string(),
+        HashMap::from_iter([("attacking".to_string(), AnimationParameter::Bool(true))]),
+        HashMap::from_iter([(
+            "attack".to_string(),
+            AnimationState2D {
+                clip: "attack".to_string(),
+                repeat: Some(1),
+                speed: 1.0,
+                priority: 0,
+            },
+        )]),
+        HashMap::from_iter([(
+            "attack".to_string(),
+            vec![AnimationTransition2D {
+                to: "attack".to_string(),
+                conditions: vec![AnimationCondition::True("attacking".to_string())],
+                exit_time: None,
+            }],
+        )]),
+        Vec::new(),
+    )
+    .unwrap();
+    let state_machine = app
+        .world_mut()
+        .resource_mut::<Assets<AnimationStateMachine2D>>()
+        .add(state_machine);
+    let entity = spawn(&mut app, AnimationPlayer2D::default());
+    app.world_mut()
+        .entity_mut(entity)
+        .insert(AnimationController2D::new(state_machine));
+
+    // The first update has no delta time
+    app.update();
+    let mut seek_times = Vec::new();
+    for _ in 0..6 {
+        app.update();
+        let player = get_player(&app, entity);
+        assert!(player.clip_playing(&attack));
+        seek_times.push((player.seek_time() * 100.0).round() as u32);
+    }
+    // Restarted only after the attack finished
+    assert_eq!(seek_times, vec![5, 10, 15, 20, 5, 10]);
+}
+
+#[test]
+fn transitions_respect_lock() {
+    let mut app = app(50);
+    let idle = add_clip(&mut app, clip(0.4));
+    let hurt = add_clip(&mut app, clip(0.4));
+    let attack = add_clip(&mut app, clip(0.2));
+    let clips = app
+        .world_mut()
+        .resource_mut::<Assets<AnimationClip2DSet>>()
+        .add(AnimationClip2DSet {
+            animations: HashMap::from_iter([
+                ("idle".to_string(), idle.clone()),
+                ("hurt".to_string(), hurt.clone()),
+            ]),
+            variants: HashMap::new(),
+        });
+    let state = |clip: &str, priority| AnimationState2D {
+        clip: clip.to_string(),
+        repeat: None,
+        speed: 1.0,
+        priority,
+    };
+    let state_machine = AnimationStateMachine2D::new(
+        clips,
+        "idle".to_string(),
+        HashMap::from_iter([("hit".to_string(), AnimationParameter::Trigger(false))]),
+        HashMap::from_iter([
+            ("idle".to_string(), state("idle", 0)),
+            ("hurt".to_string(), state("hurt", 10)),
+        ]),
+        HashMap::new(),
+        vec![AnimationTransition2D {
+            to: "hurt".to_string(),
+            conditions: vec![AnimationCondition::Triggered("hit".to_string())],
+            exit_time: None,
+        }],
+    )
+    .unwrap();
+    let state_machine = app
+        .world_mut()
+        .resource_mut::<Assets<AnimationStateMachine2D>>()
+        .add(state_machine);
+    let mut player = AnimationPlayer2D::default();
+    player.request(
+        AnimationRequest::new(attack.clone())
+            .with_priority(5)
+            .with_lock(AnimationLock::UntilFinished),
+    );
+    let entity = spawn(&mut app, player);
+    app.world_mut()
+        .entity_mut(entity)
+        .insert(AnimationController2D::new(state_machine));
+
+    // The animation clip of the initial state is played once the attack finished
+    for _ in 0..4 {
+        app.update();
+        assert!(get_player(&app, entity).clip_playing(&attack));
+        assert_eq!(controller(&mut app, entity).state(), Some("idle"));
+    }
+    update_until(&mut app, |app| get_player(app, entity).clip_playing(&idle));
+
+    // A state with a higher priority interrupts a locked animation
+    get_player_mut(&mut app, entity).request(
+        AnimationRequest::new(attack.clone())
+            .with_priority(5)
+            .with_lock(AnimationLock::UntilFinished),
+    );
+    app.update();
+    assert!(get_player(&app, entity).clip_playing(&attack));
+    controller(&mut app, entity).set_trigger("hit");
+    app.update();
+    assert!(get_player(&app, entity).clip_playing(&hurt));
+    assert_eq!(controller(&mut app, entity).state(), Some("hurt"));
+}
+
+#[test]
+fn reload_removing_current_state_starts_over() {
+    let mut app = app(50);
+    let idle = add_clip(&mut app, clip(0.4));
+    let hurt = add_clip(&mut app, clip(0.4));
+    let clips = app
+        .world_mut()
+        .resource_mut::<Assets<AnimationClip2DSet>>()
+        .add(AnimationClip2DSet {
+            animations: HashMap::from_iter([
+                ("idle".to_string(), idle.clone()),
+                ("hurt".to_string(), hurt.clone()),
+            ]),
+            variants: HashMap::new(),
+        });
+    let state = |clip: &str| AnimationState2D {
+        clip: clip.to_string(),
+        repeat: None,
+        speed: 1.0,
+        priority: 0,
+    };
+    let state_machine = AnimationStateMachine2D::new(
+        clips.clone(),
+        "idle".to_string(),
+        HashMap::from_iter([("hit".to_string(), AnimationParameter::Trigger(false))]),
+        HashMap::from_iter([
+            ("idle".to_string(), state("idle")),
+            ("hurt".to_string(), state("hurt")),
+        ]),
+        HashMap::new(),
+        vec![AnimationTransition2D {
+            to: "hurt".to_string(),
+            conditions: vec![AnimationCondition::Triggered("hit".to_string())],
+            exit_time: None,
+        }],
+    )
+    .unwrap();
+    let state_machine = app
+        .world_mut()
+        .resource_mut::<Assets<AnimationStateMachine2D>>()
+        .add(state_machine);
+    let entity = spawn(&mut app, AnimationPlayer2D::default());
+    app.world_mut()
+        .entity_mut(entity)
+        .insert(AnimationController2D::new(state_machine.clone()));
+
+    app.update();
+    controller(&mut app, entity).set_trigger("hit");
+    app.update();
+    assert_eq!(controller(&mut app, entity).state(), Some("hurt"));
+
+    // The reloaded state machine no longer has the current state
+    let reloaded = AnimationStateMachine2D::new(
+        clips,
+        "idle".to_string(),
+        HashMap::new(),
+        HashMap::from_iter([("idle".to_string(), state("idle"))]),
+        HashMap::new(),
+        Vec::new(),
+    )
+    .unwrap();
+    app.world_mut()
+        .resource_mut::<Assets<AnimationStateMachine2D>>()
+        .insert(&state_machine, reloaded);
+    app.update();
+    assert_eq!(controller(&mut app, entity).state(), Some("idle"));
+    assert!(get_player(&app, entity).clip_playing(&idle));
+}
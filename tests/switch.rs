@@ -0,0 +1,118 @@
+mod common;
+
+use bevy::prelude::*;
+use bevy_trickfilm::{
+    animation::{AnimationClip2DReady, PendingPolicy, SwitchPoint},
+    prelude::*,
+};
+use common::*;
+
+/// Player of a repeating animation clip, that requests a switch to another animation clip after the first 30ms.
+fn switch_at(at: SwitchPoint) -> (App, Entity, Handle<AnimationClip2D>) {
+    let mut app = app(30);
+    let first = add_clip(&mut app, clip(0.4));
+    let second = add_clip(&mut app, clip(0.8));
+    let mut player = AnimationPlayer2D::default();
+    player.start(first).repeat();
+    let entity = spawn(&mut app, player);
+    app.update();
+    app.update();
+    get_player_mut(&mut app, entity).play_at(second.clone(), at);
+    (app, entity, second)
+}
+
+/// Update until the switch happened, returning the seek time of the playing animation before the switch.
+fn update_until_switched(app: &mut App, entity: Entity, second: &Handle<AnimationClip2D>) -> f32 {
+    for _ in 0..100 {
+        let seek_time = get_player(app, entity).seek_time();
+        app.update();
+        if get_player(app, entity).clip_playing(second) {
+            return seek_time;
+        }
+    }
+    panic!("the switch did not happen");
+}
+
+#[test]
+fn switch_at_end_of_cycle() {
+    let (mut app, entity, second) = switch_at(SwitchPoint::EndOfCycle);
+    let seek_time = update_until_switched(&mut app, entity, &second);
+
+    assert!((seek_time - 0.39).abs() < 1e-4);
+    // The switched animation continues with the time left over
+    assert!((get_player(&app, entity).seek_time() - 0.02).abs() < 1e-4);
+    assert!(get_player(&app, entity).pending_switch().is_none());
+}
+
+#[test]
+fn switch_at_frame() {
+    let (mut app, entity, second) = switch_at(SwitchPoint::Frame(2));
+    let seek_time = update_until_switched(&mut app, entity, &second);
+
+    assert!((seek_time - 0.18).abs() < 1e-4);
+    assert!((get_player(&app, entity).seek_time() - 0.01).abs() < 1e-4);
+    assert_eq!(atlas_index(&app, entity), 0);
+}
+
+#[test]
+fn switch_at_normalized_time() {
+    let (mut app, entity, second) = switch_at(SwitchPoint::NormalizedTime(0.5));
+    let seek_time = update_until_switched(&mut app, entity, &second);
+
+    assert!((seek_time - 0.18).abs() < 1e-4);
+    assert!((get_player(&app, entity).seek_time() - 0.01).abs() < 1e-4);
+}
+
+#[test]
+fn cancel_switch() {
+    let (mut app, entity, second) = switch_at(SwitchPoint::EndOfCycle);
+    let pending_switch = get_player_mut(&mut app, entity).cancel_switch().unwrap();
+    assert_eq!(pending_switch.animation_clip(), &second);
+
+    for _ in 0..30 {
+        app.update();
+        assert!(!get_player(&app, entity).clip_playing(&second));
+    }
+}
+
+#[test]
+fn switched_animation_clip_is_ready_right_away() {
+    let (mut app, entity, second) = switch_at(SwitchPoint::EndOfCycle);
+    let mut ready = drain_events::<AnimationClip2DReady>(&mut app).len();
+    update_until(&mut app, |app| {
+        ready += drain_events::<AnimationClip2DReady>(app).len();
+        get_player(app, entity).clip_playing(&second)
+    });
+    for _ in 0..5 {
+        assert!(!get_player(&app, entity).pending());
+        app.update();
+        ready += drain_events::<AnimationClip2DReady>(&mut app).len();
+    }
+    assert_eq!(ready, 1);
+}
+
+/// Seek time of an animation clip switched to while it is still loading, after a few updates.
+fn switch_to_loading(pending_policy: PendingPolicy) -> f32 {
+    let (mut app, entity, _) = switch_at(SwitchPoint::Frame(1));
+    let loading = app
+        .world()
+        .resource::<Assets<AnimationClip2D>>()
+        .reserve_handle();
+    get_player_mut(&mut app, entity)
+        .set_pending_policy(pending_policy)
+        .play_at(loading.clone(), SwitchPoint::Frame(1));
+    update_until(&mut app, |app| {
+        get_player(app, entity).clip_playing(&loading)
+    });
+    for _ in 0..3 {
+        app.update();
+    }
+    assert!(get_player(&app, entity).pending());
+    get_player(&app, entity).seek_time()
+}
+
+#[test]
+fn leftover_follows_pending_policy() {
+    assert_eq!(switch_to_loading(PendingPolicy::StartWhenReady), 0.0);
+    assert!(switch_to_loading(PendingPolicy::Accumulate) > 0.09);
+}
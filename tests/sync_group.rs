@@ -0,0 +1,164 @@
+mod common;
+
+use bevy::{animation::RepeatAnimation, prelude::*};
+use bevy_trickfilm::{
+    animation::{AnimationGroup2D, AnimationGroups, AnimationSyncClocks, AnimationSyncGroup2D},
+    prelude::*,
+};
+use common::*;
+
+fn spawn_member(app: &mut App, animation_clip: &Handle<AnimationClip2D>) -> Entity {
+    let mut player = AnimationPlayer2D::default();
+    player.start(animation_clip.clone()).repeat();
+    let entity = spawn(app, player);
+    app.world_mut()
+        .entity_mut(entity)
+        .insert(AnimationSyncGroup2D::new("torches"));
+    entity
+}
+
+fn clock(app: &App, animation_clip: &Handle<AnimationClip2D>) -> Option<f32> {
+    app.world()
+        .resource::<AnimationSyncClocks>()
+        .clock("torches", animation_clip)
+}
+
+#[test]
+fn members_share_phase() {
+    let mut app = app(10);
+    let animation_clip = add_clip(&mut app, clip(0.4));
+    let first = spawn_member(&mut app, &animation_clip);
+    for _ in 0..7 {
+        app.update();
+    }
+    let second = spawn_member(&mut app, &animation_clip);
+
+    // The first update has no delta time, so the clock shows 10ms less than the number of updates
+    for update in 7..150 {
+        app.update();
+        let frame = (update % 40) / 10;
+        assert_eq!(atlas_index(&app, first), frame, "update {update}");
+        assert_eq!(atlas_index(&app, second), frame, "update {update}");
+    }
+}
+
+#[test]
+fn clock_per_animation_clip() {
+    let mut app = app(50);
+    let torch = add_clip(&mut app, clip(0.4));
+    let candle = add_clip(&mut app, clip(0.4));
+    spawn_member(&mut app, &torch);
+    for _ in 0..4 {
+        app.update();
+    }
+    let entity = spawn_member(&mut app, &candle);
+    app.update();
+
+    assert!((clock(&app, &torch).unwrap() - 0.2).abs() < 1e-6);
+    assert!((clock(&app, &candle).unwrap() - 0.05).abs() < 1e-6);
+    assert_eq!(get_player(&app, entity).frame(), 0);
+}
+
+#[test]
+fn clock_dropped_without_members() {
+    let mut app = app(50);
+    let torch = add_clip(&mut app, clip(0.4));
+    let candle = add_clip(&mut app, clip(0.4));
+    let entity = spawn_member(&mut app, &torch);
+    app.update();
+    assert!(clock(&app, &torch).is_some());
+
+    get_player_mut(&mut app, entity).start(candle.clone());
+    app.update();
+    assert!(clock(&app, &torch).is_none());
+    assert!(clock(&app, &candle).is_some());
+
+    app.world_mut().despawn(entity);
+    app.update();
+    assert!(clock(&app, &candle).is_none());
+}
+
+#[test]
+fn ping_pong_members_share_phase() {
+    let mut app = app(50);
+    let animation_clip = add_clip(&mut app, clip(0.4));
+    let first = spawn_member(&mut app, &animation_clip);
+    get_player_mut(&mut app, first)
+        .set_ping_pong(true)
+        .set_repeat_mode(RepeatAnimation::Forever);
+    for _ in 0..11 {
+        app.update();
+    }
+    let second = spawn_member(&mut app, &animation_clip);
+    get_player_mut(&mut app, second)
+        .set_ping_pong(true)
+        .set_repeat_mode(RepeatAnimation::Forever);
+    for _ in 0..10 {
+        app.update();
+        assert_eq!(atlas_index(&app, first), atlas_index(&app, second));
+        assert_eq!(
+            get_player(&app, first).seek_time(),
+            get_player(&app, second).seek_time()
+        );
+    }
+}
+
+#[test]
+fn clock_follows_animation_group() {
+    let mut app = app(50);
+    let animation_clip = add_clip(&mut app, clip(0.4));
+    let member = spawn_member(&mut app, &animation_clip);
+    app.world_mut()
+        .entity_mut(member)
+        .insert(AnimationGroup2D::new("world"));
+    app.world_mut()
+        .resource_mut::<AnimationGroups>()
+        .set_speed("world", 2.0);
+    for _ in 0..3 {
+        app.update();
+    }
+    // Two updates with delta time at double speed
+    assert!((clock(&app, &animation_clip).unwrap() - 0.2).abs() < 1e-4);
+    assert!((get_player(&app, member).seek_time() - 0.2).abs() < 1e-4);
+
+    app.world_mut()
+        .resource_mut::<AnimationGroups>()
+        .pause("world");
+    for _ in 0..3 {
+        app.update();
+    }
+    let paused_clock = clock(&app, &animation_clip).unwrap();
+    app.update();
+    assert_eq!(clock(&app, &animation_clip), Some(paused_clock));
+
+    // The member continues from where it was paused
+    let seek_time = get_player(&app, member).seek_time();
+    app.world_mut()
+        .resource_mut::<AnimationGroups>()
+        .resume("world");
+    app.update();
+    assert!((get_player(&app, member).seek_time() - seek_time - 0.1).abs() < 1e-4);
+}
+
+#[test]
+fn clock_follows_fastest_animation_group() {
+    // The clock speed does not depend on the order the members are spawned in
+    for speeds in [[0.5, 2.0], [2.0, 0.5]] {
+        let mut app = app(50);
+        let animation_clip = add_clip(&mut app, clip(0.4));
+        for (name, speed) in ["slow", "fast"].into_iter().zip(speeds) {
+            let member = spawn_member(&mut app, &animation_clip);
+            app.world_mut()
+                .entity_mut(member)
+                .insert(AnimationGroup2D::new(name));
+            app.world_mut()
+                .resource_mut::<AnimationGroups>()
+                .set_speed(name, speed);
+        }
+        for _ in 0..3 {
+            app.update();
+        }
+        // Two updates with delta time at double speed
+        assert!((clock(&app, &animation_clip).unwrap() - 0.2).abs() < 1e-4);
+    }
+}
@@ -0,0 +1,58 @@
+mod common;
+
+use bevy::prelude::*;
+use bevy_trickfilm::{animation::SyncMode, prelude::*};
+use common::*;
+
+fn step_clip(duration: f32, step_frame: usize) -> AnimationClip2D {
+    clip(duration)
+        .with_sync_markers([("step".to_string(), step_frame)].into_iter().collect())
+        .unwrap()
+}
+
+/// Switch to the second animation clip, once the first one played 100ms, returning the seek time right after the switch.
+fn switch_synced(first: AnimationClip2D, second: AnimationClip2D, mode: SyncMode) -> f32 {
+    let mut app = app(50);
+    let first = add_clip(&mut app, first);
+    let second = add_clip(&mut app, second);
+    let mut player = AnimationPlayer2D::default();
+    player.start(first).repeat();
+    let entity = spawn(&mut app, player);
+    for _ in 0..3 {
+        app.update();
+    }
+    assert!((get_player(&app, entity).seek_time() - 0.1).abs() < 1e-4);
+
+    get_player_mut(&mut app, entity).play_synced(second.clone(), mode);
+    app.update();
+    assert!(get_player(&app, entity).clip_playing(&second));
+    get_player(&app, entity).seek_time()
+}
+
+#[test]
+fn normalized_time_is_kept() {
+    let seek_time = switch_synced(clip(0.4), clip(0.8), SyncMode::Normalized);
+    // A quarter through the first animation clip is a quarter through the second one
+    assert!((seek_time - 0.25).abs() < 1e-4, "{seek_time}");
+}
+
+#[test]
+fn sync_marker_is_kept() {
+    let seek_time = switch_synced(
+        step_clip(0.4, 1),
+        step_clip(0.8, 3),
+        SyncMode::Marker("step".to_string()),
+    );
+    // The first animation is at its sync marker, so the second one continues from its own
+    assert!((seek_time - 0.65).abs() < 1e-4, "{seek_time}");
+}
+
+#[test]
+fn missing_sync_marker_falls_back_to_normalized_time() {
+    let seek_time = switch_synced(
+        clip(0.4),
+        step_clip(0.8, 3),
+        SyncMode::Marker("step".to_string()),
+    );
+    assert!((seek_time - 0.25).abs() < 1e-4, "{seek_time}");
+}
@@ -0,0 +1,85 @@
+mod common;
+
+use bevy_trickfilm::{
+    animation::{AnimationDrive, AnimationTempo},
+    prelude::*,
+};
+use common::*;
+
+fn assert_beat(tempo: &mut AnimationTempo, song_position: f32, beat: f32) {
+    tempo.set_song_position(song_position);
+    assert!(
+        (tempo.beat() - beat).abs() < 1e-5,
+        "beat {} at {song_position} instead of {beat}",
+        tempo.beat()
+    );
+}
+
+#[test]
+fn beats() {
+    let mut tempo = AnimationTempo::new(120.0);
+    assert_beat(&mut tempo, 1.0, 2.0);
+    assert_beat(&mut tempo, -0.5, -1.0);
+    assert_eq!(tempo.bar(), -0.25);
+}
+
+#[test]
+fn tempo_changes() {
+    let mut tempo = AnimationTempo::new(120.0).with_tempo_change(2.0, 240.0);
+    assert_beat(&mut tempo, 1.0, 2.0);
+    assert_eq!(tempo.bpm(), 120.0);
+    assert_beat(&mut tempo, 3.0, 8.0);
+    assert_eq!(tempo.bpm(), 240.0);
+
+    // Changing the tempo keeps the timing of earlier beats, even when seeking back
+    tempo.set_song_position(1.0).set_bpm(60.0);
+    assert_beat(&mut tempo, 0.5, 1.0);
+    assert_beat(&mut tempo, 1.5, 2.5);
+    assert_beat(&mut tempo, 3.0, 7.0);
+    assert_eq!(
+        tempo.tempo_changes(),
+        &[(0.0, 120.0), (1.0, 60.0), (2.0, 240.0)]
+    );
+}
+
+#[test]
+fn time_signature() {
+    assert!(
+        AnimationTempo::new(120.0)
+            .with_time_signature(0, 4)
+            .is_err()
+    );
+    assert!(
+        AnimationTempo::new(120.0)
+            .with_time_signature(3, 0)
+            .is_err()
+    );
+
+    let mut tempo = AnimationTempo::new(120.0)
+        .with_time_signature(3, 4)
+        .unwrap();
+    tempo.set_song_position(3.0);
+    assert_eq!(tempo.time_signature(), (3, 4));
+    assert_eq!(tempo.bar(), 2.0);
+}
+
+#[test]
+fn drive_follows_beats() {
+    let mut app = app(50);
+    let animation_clip = add_clip(&mut app, clip(1.0).with_beats(2.0).unwrap());
+    let mut player = AnimationPlayer2D::default();
+    player
+        .start(animation_clip)
+        .set_drive(AnimationDrive::Tempo(0.0));
+    let entity = spawn(&mut app, player);
+
+    // Each frame lasts half a beat, a quarter second at 120 bpm
+    for (song_position, frame) in [(0.0, 0), (0.3, 1), (0.8, 3), (1.1, 0), (0.6, 2)] {
+        app.world_mut()
+            .resource_mut::<AnimationTempo>()
+            .set_song_position(song_position);
+        app.update();
+        assert_eq!(get_player(&app, entity).frame(), frame);
+        assert_eq!(atlas_index(&app, entity), frame);
+    }
+}
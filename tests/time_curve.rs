@@ -0,0 +1,85 @@
+mod common;
+
+use bevy::prelude::*;
+use bevy_trickfilm::{asset::TimeCurve, prelude::*};
+use common::*;
+
+/// Displayed frame of each update of an animation clip with the time curve, until it finished.
+fn curve_frames(time_curve: TimeCurve) -> Vec<usize> {
+    let mut app = app(50);
+    let animation_clip = add_clip(&mut app, clip(0.4).with_time_curve(time_curve).unwrap());
+    let mut player = AnimationPlayer2D::default();
+    player.start(animation_clip);
+    let entity = spawn(&mut app, player);
+
+    let mut frames = Vec::new();
+    update_until(&mut app, |app| {
+        frames.push(atlas_index(app, entity));
+        get_player(app, entity).finished()
+    });
+    frames
+}
+
+#[test]
+fn linear_curve() {
+    assert_eq!(curve_frames(TimeCurve::Linear), [0, 0, 1, 1, 2, 2, 3, 3, 3]);
+}
+
+#[test]
+fn ease_in_and_out() {
+    // Half way through the playback, the clip is at 12.5% and 87.5% of its duration
+    let ease_in = curve_frames(TimeCurve::EaseIn);
+    let ease_out = curve_frames(TimeCurve::EaseOut);
+    assert_eq!(ease_in[4], 0);
+    assert_eq!(ease_out[4], 3);
+
+    // The duration of the animation clip does not change
+    assert_eq!(ease_in.len(), 9);
+    assert_eq!(ease_out.len(), 9);
+    assert_eq!(ease_in.last(), Some(&3));
+    assert!(ease_in.is_sorted());
+    assert!(ease_out.is_sorted());
+}
+
+#[test]
+fn piecewise_curve_holds_frame() {
+    let frames = curve_frames(TimeCurve::Piecewise(vec![
+        (0.0, 0.0),
+        (0.25, 0.25),
+        (0.75, 0.25),
+        (1.0, 1.0),
+    ]));
+    assert_eq!(&frames[2..7], &[1, 1, 1, 1, 1]);
+    assert_eq!(frames.last(), Some(&3));
+}
+
+#[test]
+fn invalid_piecewise_curve() {
+    assert!(
+        clip(0.4)
+            .with_time_curve(TimeCurve::Piecewise(vec![
+                (0.0, 0.0),
+                (0.5, 0.6),
+                (0.5, 1.0)
+            ]))
+            .is_err()
+    );
+    assert!(
+        clip(0.4)
+            .with_time_curve(TimeCurve::Piecewise(vec![
+                (0.0, 0.0),
+                (0.5, 0.6),
+                (1.0, 0.5)
+            ]))
+            .is_err()
+    );
+}
+
+#[test]
+fn piecewise_curve_without_segments_is_linear() {
+    for points in [vec![], vec![(0.5, 0.5)]] {
+        let time_curve = TimeCurve::Piecewise(points);
+        assert_eq!(time_curve.sample(0.25), 0.25);
+        assert_eq!(time_curve.inverse(0.75), 0.75);
+    }
+}
@@ -0,0 +1,188 @@
+mod common;
+
+use bevy::{
+    animation::RepeatAnimation, platform::collections::HashMap, prelude::*, reflect::PartialReflect,
+};
+use bevy_trickfilm::{
+    animation::{FinishBehaviour, SeekEvents},
+    asset::Keyframes,
+    prelude::*,
+};
+use common::*;
+
+#[derive(Debug, Clone, Event, Reflect, AnimationEvent)]
+struct Step(usize);
+
+/// Paused player of an animation clip with four frames of 0.1 seconds.
+fn paused(repeat: RepeatAnimation) -> (App, Entity) {
+    let mut app = app(50);
+    let animation_clip = add_clip(&mut app, clip(0.4));
+    let mut player = AnimationPlayer2D::default();
+    player.start(animation_clip).set_repeat_mode(repeat).pause();
+    let entity = spawn(&mut app, player);
+    app.update();
+    (app, entity)
+}
+
+#[test]
+fn seek_to_last_frame() {
+    let (mut app, entity) = paused(RepeatAnimation::Never);
+    get_player_mut(&mut app, entity).seek_to_frame(3, &clip(0.4));
+    assert_eq!(get_player(&app, entity).frame(), 3);
+    app.update();
+    assert_eq!(get_player(&app, entity).frame(), 3);
+    assert_eq!(atlas_index(&app, entity), 3);
+}
+
+#[test]
+fn seek_to_start_of_last_frame() {
+    let (mut app, entity) = paused(RepeatAnimation::Never);
+    // The seek time matches the keyframe timestamp of the last frame exactly
+    get_player_mut(&mut app, entity).seek_to(0.3);
+    app.update();
+    assert_eq!(get_player(&app, entity).frame(), 3);
+    assert_eq!(atlas_index(&app, entity), 3);
+}
+
+#[test]
+fn seek_to_normalized() {
+    let (mut app, entity) = paused(RepeatAnimation::Never);
+    get_player_mut(&mut app, entity).seek_to_normalized(0.6, &clip(0.4));
+    app.update();
+    assert_eq!(atlas_index(&app, entity), 2);
+}
+
+#[test]
+fn apply_frame_index() {
+    let (mut app, entity) = paused(RepeatAnimation::Never);
+    let mut entity_mut = app.world_mut().entity_mut(entity);
+    let mut sprite = entity_mut.take::<Sprite>().unwrap();
+    get_player_mut(&mut app, entity)
+        .seek_to_frame(2, &clip(0.4))
+        .apply_frame_index(&clip(0.4), &mut sprite);
+    assert_eq!(sprite.texture_atlas.unwrap().index, 2);
+}
+
+#[test]
+fn next_frame_finishes() {
+    let (mut app, entity) = paused(RepeatAnimation::Never);
+    get_player_mut(&mut app, entity).set_finish_behaviour(FinishBehaviour::Hide);
+    let animation_clip = clip(0.4);
+    for _ in 0..3 {
+        get_player_mut(&mut app, entity).next_frame(&animation_clip);
+        app.update();
+    }
+    assert_eq!(atlas_index(&app, entity), 3);
+    assert!(!get_player(&app, entity).finished());
+
+    get_player_mut(&mut app, entity).next_frame(&animation_clip);
+    assert!(get_player(&app, entity).just_finished());
+    app.update();
+    let player = get_player(&app, entity);
+    assert!(player.just_finished());
+    assert_eq!(player.completions(), 1);
+    assert_eq!(atlas_index(&app, entity), 3);
+    assert_eq!(
+        app.world().get::<Visibility>(entity),
+        Some(&Visibility::Hidden)
+    );
+
+    // A finished animation holds the last frame
+    get_player_mut(&mut app, entity).next_frame(&animation_clip);
+    app.update();
+    assert_eq!(get_player(&app, entity).completions(), 1);
+    assert_eq!(atlas_index(&app, entity), 3);
+}
+
+#[test]
+fn next_frame_wraps_around() {
+    let (mut app, entity) = paused(RepeatAnimation::Forever);
+    get_player_mut(&mut app, entity).seek_to_frame(3, &clip(0.4));
+    app.update();
+    get_player_mut(&mut app, entity).next_frame(&clip(0.4));
+    app.update();
+    let player = get_player(&app, entity);
+    assert!(player.just_finished_cycle());
+    assert_eq!(player.completions(), 1);
+    assert_eq!(atlas_index(&app, entity), 0);
+
+    app.update();
+    assert!(!get_player(&app, entity).just_finished_cycle());
+}
+
+#[test]
+fn previous_frame_wraps_around() {
+    let (mut app, entity) = paused(RepeatAnimation::Forever);
+    get_player_mut(&mut app, entity)
+        .next_frame(&clip(0.4))
+        .previous_frame(&clip(0.4))
+        .previous_frame(&clip(0.4));
+    app.update();
+    assert_eq!(get_player(&app, entity).frame(), 3);
+    assert_eq!(get_player(&app, entity).completions(), 0);
+    assert_eq!(atlas_index(&app, entity), 3);
+}
+
+#[test]
+fn previous_frame_reverts_finish_once() {
+    let mut app = app(50);
+    let single_frame =
+        || AnimationClip2D::new(None, Keyframes::KeyframesRange(0..1), 0.1, None).unwrap();
+    let animation_clip = add_clip(&mut app, single_frame());
+    let mut player = AnimationPlayer2D::default();
+    player
+        .start(animation_clip)
+        .set_repeat_mode(RepeatAnimation::Count(2));
+    let entity = spawn(&mut app, player);
+    update_until(&mut app, |app| get_player(app, entity).finished());
+    assert_eq!(get_player(&app, entity).completions(), 2);
+
+    get_player_mut(&mut app, entity).previous_frame(&single_frame());
+    let player = get_player(&app, entity);
+    assert_eq!(player.completions(), 1);
+    assert!(!player.finished());
+}
+
+#[test]
+fn seek_events() {
+    let mut app = app(50);
+    app.add_animation_event::<Step>();
+    let new_clip = || {
+        let events: HashMap<usize, Vec<Box<dyn PartialReflect>>> = (0..4)
+            .map(|frame| {
+                (
+                    frame,
+                    vec![Box::new(Step(frame)) as Box<dyn PartialReflect>],
+                )
+            })
+            .collect();
+        AnimationClip2D::new(None, Keyframes::KeyframesRange(0..4), 0.4, Some(events)).unwrap()
+    };
+    let animation_clip = add_clip(&mut app, new_clip());
+    let mut player = AnimationPlayer2D::default();
+    player.start(animation_clip).pause();
+    let entity = spawn(&mut app, player);
+    app.update();
+    let frames = |app: &mut App| {
+        drain_events::<Step>(app)
+            .into_iter()
+            .map(|Step(frame)| frame)
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(frames(&mut app), vec![0]);
+
+    // Suppressed by default
+    get_player_mut(&mut app, entity).seek_to_frame(2, &new_clip());
+    app.update();
+    assert_eq!(frames(&mut app), Vec::<usize>::new());
+
+    get_player_mut(&mut app, entity)
+        .set_seek_events(SeekEvents::Fire)
+        .seek_to_frame(0, &new_clip());
+    app.update();
+    assert_eq!(frames(&mut app), vec![1, 0]);
+
+    get_player_mut(&mut app, entity).previous_frame(&new_clip());
+    app.update();
+    assert_eq!(frames(&mut app), vec![3]);
+}
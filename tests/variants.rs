@@ -0,0 +1,127 @@
+mod common;
+
+use bevy::{asset::AssetPlugin, platform::collections::HashMap, prelude::*};
+use bevy_trickfilm::{
+    animation::{AnimationClip2DReady, AnimationClip2DSetHandle},
+    prelude::*,
+};
+use common::*;
+
+fn spawn_variants(app: &mut App) -> (Entity, [Handle<AnimationClip2D>; 2]) {
+    let left = add_clip(app, clip(0.2));
+    let right = add_clip(app, clip(0.2));
+    let set = app
+        .world_mut()
+        .resource_mut::<Assets<AnimationClip2DSet>>()
+        .add(AnimationClip2DSet {
+            animations: HashMap::from_iter([
+                ("left".to_string(), left.clone()),
+                ("right".to_string(), right.clone()),
+            ]),
+            variants: HashMap::from_iter([(
+                "idle".to_string(),
+                vec![("left".to_string(), 1.0), ("right".to_string(), 1.0)],
+            )]),
+        });
+    let mut player = AnimationPlayer2D::default();
+    player.set_variant_seed(3).start_named("idle").repeat();
+    let entity = spawn(app, player);
+    app.world_mut()
+        .entity_mut(entity)
+        .insert(AnimationClip2DSetHandle(set));
+    (entity, [left, right])
+}
+
+#[test]
+fn variants_report_cycles() {
+    let mut app = app(50);
+    let (entity, variants) = spawn_variants(&mut app);
+
+    let mut cycles = 0;
+    let mut picked = Vec::new();
+    for _ in 0..41 {
+        app.update();
+        let player = get_player(&app, entity);
+        if player.just_finished_cycle() {
+            cycles += 1;
+        }
+        let animation_clip = player.animation_clip().clone();
+        if picked.last() != Some(&animation_clip) {
+            picked.push(animation_clip);
+        }
+    }
+
+    // 2 seconds with cycles of 0.2 seconds
+    assert_eq!(cycles, 10);
+    assert_eq!(get_player(&app, entity).completions(), 10);
+    assert!(variants.iter().all(|variant| picked.contains(variant)));
+    assert_eq!(get_player(&app, entity).animation_name(), Some("idle"));
+}
+
+#[test]
+fn variants_are_ready_once() {
+    let mut app = app(50);
+    let (entity, _) = spawn_variants(&mut app);
+
+    let mut ready = 0;
+    for _ in 0..41 {
+        app.update();
+        assert!(!get_player(&app, entity).pending());
+        ready += drain_events::<AnimationClip2DReady>(&mut app).len();
+    }
+    assert_eq!(ready, 1);
+}
+
+#[test]
+fn variant_picks_are_not_pending_switches() {
+    let mut app = app(50);
+    let (entity, variants) = spawn_variants(&mut app);
+
+    let mut picked = Vec::new();
+    for _ in 0..41 {
+        app.update();
+        assert!(get_player(&app, entity).pending_switch().is_none());
+        assert!(get_player_mut(&mut app, entity).cancel_switch().is_none());
+        let animation_clip = get_player(&app, entity).animation_clip().clone();
+        if picked.last() != Some(&animation_clip) {
+            picked.push(animation_clip);
+        }
+    }
+    assert!(variants.iter().all(|variant| picked.contains(variant)));
+}
+
+#[test]
+fn variant_group_named_like_a_clip_fails() {
+    // The animation clip set is loaded from a temporary asset folder
+    let folder = std::env::temp_dir().join("bevy_trickfilm_variant_group_named_like_a_clip");
+    std::fs::create_dir_all(&folder).unwrap();
+    std::fs::write(
+        folder.join("duplicate.trickfilm.ron"),
+        r#"{
+            "idle": (keyframes: KeyframesVec([0]), duration: 0.1),
+            "run": (keyframes: KeyframesRange((start: 1, end: 7)), duration: 0.6),
+            "idle": (variants: [("run", 1.0)]),
+        }"#,
+    )
+    .unwrap();
+    let mut app = App::new();
+    app.add_plugins((
+        MinimalPlugins,
+        AssetPlugin {
+            file_path: folder.to_string_lossy().into_owned(),
+            ..Default::default()
+        },
+        Animation2DPlugin,
+    ));
+    let set: Handle<AnimationClip2DSet> = app
+        .world()
+        .resource::<AssetServer>()
+        .load("duplicate.trickfilm.ron");
+
+    update_until(&mut app, |app| {
+        app.world()
+            .resource::<AssetServer>()
+            .load_state(&set)
+            .is_failed()
+    });
+}
@@ -0,0 +1,153 @@
+mod common;
+
+use bevy::prelude::*;
+use bevy_trickfilm::{
+    animation::{AnimationClip2DSetHandle, AnimationVariation2D, RestingAnimation2D, SwitchPoint},
+    prelude::*,
+};
+use common::*;
+
+/// Variation that doubles the speed of every started animation, without a start offset.
+fn double_speed() -> AnimationVariation2D {
+    AnimationVariation2D::new()
+        .with_max_offset(0.0)
+        .with_speed_range(2.0, 2.0)
+}
+
+fn spawn_varied(
+    app: &mut App,
+    player: AnimationPlayer2D,
+    variation: AnimationVariation2D,
+) -> Entity {
+    let entity = spawn(app, player);
+    app.world_mut().entity_mut(entity).insert(variation);
+    entity
+}
+
+#[test]
+fn seeded_variation_is_deterministic() {
+    let mut app = app(50);
+    let animation_clip = add_clip(&mut app, clip(0.4));
+    let variation = AnimationVariation2D::new()
+        .with_speed_range(0.5, 1.5)
+        .with_seed(7);
+    let mut player = AnimationPlayer2D::default();
+    player.start(animation_clip.clone()).repeat();
+    let first = spawn_varied(&mut app, player.clone(), variation.clone());
+    let second = spawn_varied(&mut app, player, variation.clone().with_seed(8));
+    app.update();
+
+    let seek_time = get_player(&app, first).seek_time();
+    let speed = get_player(&app, first).speed();
+    assert_ne!(seek_time, get_player(&app, second).seek_time());
+
+    // Entities with the same seed vary the same way
+    let mut player = AnimationPlayer2D::default();
+    player.start(animation_clip).repeat();
+    let third = spawn_varied(&mut app, player, variation.with_seed(7));
+    app.update();
+    assert_eq!(get_player(&app, third).speed(), speed);
+    assert_eq!(
+        get_player(&app, first).seek_time(),
+        get_player(&app, third).seek_time()
+    );
+    assert_ne!(seek_time, 0.0);
+}
+
+#[test]
+fn queued_start_varies() {
+    let mut app = app(50);
+    let first = add_clip(&mut app, clip(0.1));
+    let second = add_clip(&mut app, clip(0.4));
+    let mut player = AnimationPlayer2D::default();
+    player.start(first).enqueue(second.clone());
+    let entity = spawn_varied(&mut app, player, double_speed());
+    update_until(&mut app, |app| {
+        get_player(app, entity).clip_playing(&second)
+    });
+    // Animations started during the animation update vary with the next update
+    app.update();
+
+    assert_eq!(get_player(&app, entity).speed(), 2.0);
+}
+
+#[test]
+fn resting_start_varies() {
+    let mut app = app(50);
+    let first = add_clip(&mut app, clip(0.1));
+    let resting = add_clip(&mut app, clip(0.4));
+    let mut player = AnimationPlayer2D::default();
+    player.start(first);
+    let entity = spawn_varied(&mut app, player, double_speed());
+    app.world_mut()
+        .entity_mut(entity)
+        .insert(RestingAnimation2D::new(resting.clone()));
+    update_until(&mut app, |app| {
+        get_player(app, entity).clip_playing(&resting)
+    });
+    app.update();
+
+    assert_eq!(get_player(&app, entity).speed(), 2.0);
+}
+
+#[test]
+fn switch_varies() {
+    let mut app = app(50);
+    let first = add_clip(&mut app, clip(0.4));
+    let second = add_clip(&mut app, clip(0.4));
+    let mut player = AnimationPlayer2D::default();
+    player.start(first).repeat();
+    let entity = spawn_varied(&mut app, player, double_speed());
+    app.update();
+    get_player_mut(&mut app, entity).play_at(second.clone(), SwitchPoint::EndOfCycle);
+    update_until(&mut app, |app| {
+        get_player(app, entity).clip_playing(&second)
+    });
+    app.update();
+
+    // The switch keeps the varied speed of the playing animation, which is varied again
+    assert_eq!(get_player(&app, entity).speed(), 4.0);
+}
+
+#[test]
+fn named_start_varies() {
+    let mut app = app(50);
+    let set = app
+        .world()
+        .resource::<AssetServer>()
+        .load("gabe-idle-run-animation.trickfilm.ron");
+    let mut player = AnimationPlayer2D::default();
+    player.start_named("run");
+    let entity = spawn_varied(&mut app, player, double_speed());
+    app.world_mut()
+        .entity_mut(entity)
+        .insert(AnimationClip2DSetHandle(set));
+    update_until(&mut app, |app| get_player(app, entity).duration().is_some());
+    app.update();
+
+    assert_eq!(get_player(&app, entity).speed(), 2.0);
+}
+
+#[test]
+fn variation_added_later_does_not_vary_playing_animation() {
+    let mut app = app(50);
+    let animation_clip = add_clip(&mut app, clip(0.4));
+    let mut player = AnimationPlayer2D::default();
+    player.start(animation_clip.clone()).repeat();
+    let entity = spawn(&mut app, player);
+    app.update();
+    app.update();
+    let seek_time = get_player(&app, entity).seek_time();
+
+    app.world_mut().entity_mut(entity).insert(double_speed());
+    app.update();
+    assert_eq!(get_player(&app, entity).speed(), 1.0);
+    assert!((get_player(&app, entity).seek_time() - seek_time - 0.05).abs() < 1e-5);
+
+    // Animations started afterwards vary
+    get_player_mut(&mut app, entity)
+        .start(animation_clip)
+        .repeat();
+    app.update();
+    assert_eq!(get_player(&app, entity).speed(), 2.0);
+}